@@ -1 +1,166 @@
-// TODO
+use crate::{
+    cmp::slice_2d_eq,
+    index::{Slice2DIndex, Slice2DIndexMut},
+    iter::Slice2DIter,
+    slice::{Shape2D, Slice2D, Slice2DMut, SlicePtr, SlicePtrMut},
+    strided::{StridedSlice, StridedSliceMut},
+};
+use core::{
+    array,
+    convert::From,
+    mem::ManuallyDrop,
+    ops::{Index, IndexMut},
+    ptr,
+};
+
+// compile time boundary check, evaluated when `OK` is referenced
+struct AssertLess<const I: usize, const N: usize>;
+
+impl<const I: usize, const N: usize> AssertLess<I, N> {
+    const OK: () = assert!(I < N, "out of range");
+}
+
+#[derive(Hash, Clone, Copy, Debug)]
+pub struct Array2D<T, const R: usize, const C: usize> {
+    data: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> Array2D<T, R, C> {
+    #[inline]
+    pub const fn new(data: [[T; C]; R]) -> Array2D<T, R, C> {
+        Array2D { data }
+    }
+    pub fn from_fn<F>(mut f: F) -> Array2D<T, R, C>
+    where
+        F: FnMut((usize, usize)) -> T,
+    {
+        Array2D {
+            data: array::from_fn(|i| array::from_fn(|j| f((i, j)))),
+        }
+    }
+    #[inline]
+    pub fn into_inner(self) -> [[T; C]; R] {
+        self.data
+    }
+
+    #[inline]
+    pub fn row<const I: usize>(&self) -> &[T; C] {
+        let () = AssertLess::<I, R>::OK;
+        &self.data[I]
+    }
+    #[inline]
+    pub fn row_mut<const I: usize>(&mut self) -> &mut [T; C] {
+        let () = AssertLess::<I, R>::OK;
+        &mut self.data[I]
+    }
+    #[inline]
+    pub fn col<const J: usize>(&self) -> StridedSlice<'_, T> {
+        let () = AssertLess::<J, C>::OK;
+        unsafe { StridedSlice::from_col_unchecked(self, J) }
+    }
+    #[inline]
+    pub fn col_mut<const J: usize>(&mut self) -> StridedSliceMut<'_, T> {
+        let () = AssertLess::<J, C>::OK;
+        unsafe { StridedSliceMut::from_col_unchecked(self, J) }
+    }
+
+    pub fn transpose(self) -> Array2D<T, C, R> {
+        let data = ManuallyDrop::new(self.data);
+        // every element is read exactly once, and `ptr::read` never panics,
+        // so nothing can be dropped twice
+        Array2D {
+            data: array::from_fn(|j| array::from_fn(|i| unsafe { ptr::read(&data[i][j]) })),
+        }
+    }
+
+    #[inline]
+    pub fn as_slice_2d(&self) -> Slice2D<'_, T> {
+        unsafe { Slice2D::from_raw_parts(self.get_slice_ptr(), C, R, C) }
+    }
+    #[inline]
+    pub fn as_slice_2d_mut(&mut self) -> Slice2DMut<'_, T> {
+        unsafe { Slice2DMut::from_raw_parts(self.get_slice_ptr_mut(), C, R, C) }
+    }
+}
+
+impl<T: Default, const R: usize, const C: usize> Default for Array2D<T, R, C> {
+    fn default() -> Self {
+        Array2D::from_fn(|_| T::default())
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Array2D<T, R, C> {
+    fn from(data: [[T; C]; R]) -> Self {
+        Array2D::new(data)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Shape2D for Array2D<T, R, C> {
+    #[inline(always)]
+    fn get_row_stride(&self) -> isize {
+        C as isize
+    }
+
+    #[inline(always)]
+    fn get_col_stride(&self) -> isize {
+        1
+    }
+
+    #[inline(always)]
+    fn get_row(&self) -> usize {
+        R
+    }
+
+    #[inline(always)]
+    fn get_col(&self) -> usize {
+        C
+    }
+}
+impl<T, const R: usize, const C: usize> SlicePtr<T> for Array2D<T, R, C> {
+    fn get_slice_ptr(&self) -> *const T {
+        self.data.as_ptr() as *const T
+    }
+}
+impl<T, const R: usize, const C: usize> SlicePtrMut<T> for Array2D<T, R, C> {
+    fn get_slice_ptr_mut(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut T
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize> From<&'a Array2D<T, R, C>> for Slice2D<'a, T> {
+    fn from(a: &'a Array2D<T, R, C>) -> Self {
+        a.as_slice_2d()
+    }
+}
+impl<'a, T, const R: usize, const C: usize> From<&'a mut Array2D<T, R, C>> for Slice2DMut<'a, T> {
+    fn from(a: &'a mut Array2D<T, R, C>) -> Self {
+        a.as_slice_2d_mut()
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Array2D<T, R, C> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: (usize, usize)) -> &T {
+        Slice2DIndex::index(index, self)
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Array2D<T, R, C> {
+    #[inline]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        Slice2DIndexMut::index_mut(index, self)
+    }
+}
+
+impl<T, A, const R: usize, const C: usize> PartialEq<A> for Array2D<T, R, C>
+where
+    T: PartialEq,
+    A: Shape2D + SlicePtr<T> + Slice2DIter<T, A>,
+{
+    fn eq(&self, other: &A) -> bool {
+        slice_2d_eq(self, other)
+    }
+}
+impl<T: Eq, const R: usize, const C: usize> Eq for Array2D<T, R, C> {}
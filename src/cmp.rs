@@ -1,6 +1,7 @@
 use crate::{
     iter::Slice2DIter,
     slice::{Shape2D, Slice2D, Slice2DMut, SlicePtr},
+    utils::is_row_contiguous,
 };
 
 pub fn slice_2d_eq<T, A, B>(a: &A, b: &B) -> bool
@@ -11,10 +12,16 @@ where
 {
     a.get_row() == b.get_row()
         && a.get_col() == b.get_col()
-        && (a.get_slice_ptr() == b.get_slice_ptr()
-            || a.row_slice_iter()
-                .zip(b.row_slice_iter())
-                .all(|(a, b)| a == b))
+        && ((a.get_slice_ptr() == b.get_slice_ptr()
+            && a.get_row_stride() == b.get_row_stride()
+            && a.get_col_stride() == b.get_col_stride())
+            || if is_row_contiguous(a) && is_row_contiguous(b) {
+                a.row_slice_iter()
+                    .zip(b.row_slice_iter())
+                    .all(|(a, b)| a == b)
+            } else {
+                a.row_iter().zip(b.row_iter()).all(|(a, b)| a.eq(b))
+            })
 }
 
 impl<'a, T, A> PartialEq<A> for Slice2D<'a, T>
@@ -0,0 +1,211 @@
+use crate::{
+    cmp::slice_2d_eq,
+    index::{Slice2DIndex, Slice2DIndexMut},
+    iter::Slice2DIter,
+    slice::{
+        Shape2D, Shape2DExt, Slice2D, Slice2DMut, Slice2DRaw, Slice2DRawRef, Slice2DRawRefMut,
+        SlicePtr, SlicePtrMut,
+    },
+};
+use core::{
+    convert::From,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+// column-major counterparts of `Slice2D` and `Slice2DMut`, column `c`
+// starts at `c * base_row` in the underlying slice
+
+#[derive(Clone, Default, Debug)]
+pub struct ColMajorSlice2D<'a, T> {
+    raw: Slice2DRaw<T>,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> ColMajorSlice2D<'a, T> {
+    pub fn from_slice(slice: &[T], row: usize, col: usize) -> ColMajorSlice2D<'_, T> {
+        assert!(
+            row.checked_mul(col).is_some_and(|n| n <= slice.len()),
+            "slice does not contain enough space."
+        );
+        unsafe { ColMajorSlice2D::from_raw_parts(slice.as_ptr(), row, row, col) }
+    }
+    /// # Safety
+    /// `slice` must point to `col` columns of `row` elements, the columns
+    /// `base_row` elements apart, valid for reads for `'b` and not mutated
+    /// during it.
+    pub unsafe fn from_raw_parts<'b>(
+        slice: *const T,
+        base_row: usize,
+        row: usize,
+        col: usize,
+    ) -> ColMajorSlice2D<'b, T> {
+        ColMajorSlice2D {
+            raw: Slice2DRaw::from_raw_parts(slice, 1, base_row as isize, row, col),
+            _marker: PhantomData,
+        }
+    }
+}
+impl<'a, T> Slice2DRawRef for ColMajorSlice2D<'a, T> {
+    type DataT = T;
+
+    #[inline(always)]
+    fn get_slice_2d_raw(&self) -> &Slice2DRaw<Self::DataT> {
+        &self.raw
+    }
+}
+impl<'a, T> SlicePtr<T> for ColMajorSlice2D<'a, T> {
+    fn get_slice_ptr(&self) -> *const T {
+        self.raw.slice
+    }
+}
+impl<'a, T> From<ColMajorSlice2DMut<'a, T>> for ColMajorSlice2D<'a, T> {
+    fn from(s: ColMajorSlice2DMut<'a, T>) -> Self {
+        ColMajorSlice2D {
+            raw: s.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+impl<'a, T> From<ColMajorSlice2D<'a, T>> for Slice2D<'a, T> {
+    fn from(s: ColMajorSlice2D<'a, T>) -> Self {
+        unsafe { Slice2D::from_slice_2d_raw(s.raw) }
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct ColMajorSlice2DMut<'a, T> {
+    raw: Slice2DRaw<T>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> ColMajorSlice2DMut<'a, T> {
+    pub fn from_slice(slice: &mut [T], row: usize, col: usize) -> ColMajorSlice2DMut<'_, T> {
+        assert!(
+            row.checked_mul(col).is_some_and(|n| n <= slice.len()),
+            "slice does not contain enough space."
+        );
+        unsafe { ColMajorSlice2DMut::from_raw_parts(slice.as_mut_ptr(), row, row, col) }
+    }
+    /// # Safety
+    /// `slice` must point to `col` columns of `row` elements, the columns
+    /// `base_row` elements apart, with `base_row >= row` if `col > 1`, valid for
+    /// reads and writes for `'b` and not accessed through any other pointer
+    /// during it.
+    pub unsafe fn from_raw_parts<'b>(
+        slice: *mut T,
+        base_row: usize,
+        row: usize,
+        col: usize,
+    ) -> ColMajorSlice2DMut<'b, T> {
+        ColMajorSlice2DMut {
+            raw: Slice2DRaw::from_raw_parts(slice, 1, base_row as isize, row, col),
+            _marker: PhantomData,
+        }
+    }
+}
+impl<'a, T> SlicePtr<T> for ColMajorSlice2DMut<'a, T> {
+    fn get_slice_ptr(&self) -> *const T {
+        self.raw.slice
+    }
+}
+impl<'a, T> SlicePtrMut<T> for ColMajorSlice2DMut<'a, T> {
+    fn get_slice_ptr_mut(&mut self) -> *mut T {
+        self.raw.slice as *mut T
+    }
+}
+impl<'a, T> Slice2DRawRef for ColMajorSlice2DMut<'a, T> {
+    type DataT = T;
+
+    #[inline(always)]
+    fn get_slice_2d_raw(&self) -> &Slice2DRaw<Self::DataT> {
+        &self.raw
+    }
+}
+impl<'a, T> Slice2DRawRefMut for ColMajorSlice2DMut<'a, T> {
+    type DataT = T;
+
+    #[inline(always)]
+    fn get_slice_2d_raw_mut(&mut self) -> &mut Slice2DRaw<Self::DataT> {
+        &mut self.raw
+    }
+}
+impl<'a, T> From<ColMajorSlice2DMut<'a, T>> for Slice2DMut<'a, T> {
+    fn from(s: ColMajorSlice2DMut<'a, T>) -> Self {
+        unsafe { Slice2DMut::from_slice_2d_raw(s.raw) }
+    }
+}
+
+impl<'a, T: 'a> Index<(usize, usize)> for ColMajorSlice2D<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: (usize, usize)) -> &T {
+        index.index(self)
+    }
+}
+
+impl<'a, T: 'a> Index<(usize, usize)> for ColMajorSlice2DMut<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: (usize, usize)) -> &T {
+        index.index(self)
+    }
+}
+
+impl<'a, T: 'a> IndexMut<(usize, usize)> for ColMajorSlice2DMut<'a, T> {
+    #[inline]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        index.index_mut(self)
+    }
+}
+
+impl<'a, T, A> PartialEq<A> for ColMajorSlice2D<'a, T>
+where
+    T: PartialEq,
+    A: Shape2D + SlicePtr<T> + Slice2DIter<T, A>,
+{
+    fn eq(&self, other: &A) -> bool {
+        slice_2d_eq(self, other)
+    }
+}
+impl<'a, T: Eq> Eq for ColMajorSlice2D<'a, T> {}
+
+// by shape and elements, like `PartialEq`
+impl<'a, T: Hash> Hash for ColMajorSlice2D<'a, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_elems(self, state)
+    }
+}
+
+impl<'a, T, A> PartialEq<A> for ColMajorSlice2DMut<'a, T>
+where
+    T: PartialEq,
+    A: Shape2D + SlicePtr<T> + Slice2DIter<T, A>,
+{
+    fn eq(&self, other: &A) -> bool {
+        slice_2d_eq(self, other)
+    }
+}
+impl<'a, T: Eq> Eq for ColMajorSlice2DMut<'a, T> {}
+
+impl<'a, T: Hash> Hash for ColMajorSlice2DMut<'a, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_elems(self, state)
+    }
+}
+
+// the rows are strided, so the elements are hashed one by one
+fn hash_elems<T, S, H>(s: &S, state: &mut H)
+where
+    T: Hash,
+    S: Shape2D + SlicePtr<T>,
+    H: Hasher,
+{
+    s.get_shape().hash(state);
+    for e in s.row_iter().flatten() {
+        e.hash(state);
+    }
+}
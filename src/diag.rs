@@ -0,0 +1,77 @@
+use crate::{
+    slice::{Shape2D, SlicePtr, SlicePtrMut},
+    strided::{StridedSlice, StridedSliceMut},
+    utils::calc_2d_index,
+};
+
+// `k > 0` selects a diagonal above the main one, `k < 0` one below it.
+// anti-diagonals run from the top right to the bottom left, and are
+// counted the same way on the view with its columns mirrored.
+pub trait Slice2DDiag<T>: Shape2D + SlicePtr<T> {
+    fn diag(&self, k: isize) -> StridedSlice<'_, T>;
+    fn anti_diag(&self, k: isize) -> StridedSlice<'_, T>;
+}
+
+pub trait Slice2DDiagMut<T>: Shape2D + SlicePtrMut<T> {
+    fn diag_mut(&mut self, k: isize) -> StridedSliceMut<'_, T>;
+    fn anti_diag_mut(&mut self, k: isize) -> StridedSliceMut<'_, T>;
+}
+
+// offset of the first element, length and stride of a diagonal
+fn calc_diag<S: Shape2D>(slice: &S, k: isize, anti: bool) -> (isize, usize, isize) {
+    let (row, col) = (slice.get_row(), slice.get_col());
+    let d = k.unsigned_abs();
+    let (r, c, len) = if k >= 0 {
+        (0, d, row.min(col.saturating_sub(d)))
+    } else {
+        (d, 0, row.saturating_sub(d).min(col))
+    };
+    if len == 0 {
+        return (0, 0, 0);
+    }
+    if anti {
+        (
+            calc_2d_index(r, col - 1 - c, slice),
+            len,
+            slice.get_row_stride() - slice.get_col_stride(),
+        )
+    } else {
+        (
+            calc_2d_index(r, c, slice),
+            len,
+            slice.get_row_stride() + slice.get_col_stride(),
+        )
+    }
+}
+
+impl<T, S> Slice2DDiag<T> for S
+where
+    S: Shape2D + SlicePtr<T>,
+{
+    fn diag(&self, k: isize) -> StridedSlice<'_, T> {
+        let (offset, len, stride) = calc_diag(self, k, false);
+        unsafe { StridedSlice::from_raw_parts(self.get_slice_ptr().offset(offset), len, stride) }
+    }
+    fn anti_diag(&self, k: isize) -> StridedSlice<'_, T> {
+        let (offset, len, stride) = calc_diag(self, k, true);
+        unsafe { StridedSlice::from_raw_parts(self.get_slice_ptr().offset(offset), len, stride) }
+    }
+}
+
+impl<T, S> Slice2DDiagMut<T> for S
+where
+    S: Shape2D + SlicePtrMut<T>,
+{
+    fn diag_mut(&mut self, k: isize) -> StridedSliceMut<'_, T> {
+        let (offset, len, stride) = calc_diag(self, k, false);
+        unsafe {
+            StridedSliceMut::from_raw_parts(self.get_slice_ptr_mut().offset(offset), len, stride)
+        }
+    }
+    fn anti_diag_mut(&mut self, k: isize) -> StridedSliceMut<'_, T> {
+        let (offset, len, stride) = calc_diag(self, k, true);
+        unsafe {
+            StridedSliceMut::from_raw_parts(self.get_slice_ptr_mut().offset(offset), len, stride)
+        }
+    }
+}
@@ -21,15 +21,13 @@ where
     where
         T: Clone,
     {
-        self.row_iter_mut()
-            .flatten()
-            .for_each(|e| *e = value.clone());
+        self.iter_mut().for_each(|e| *e = value.clone());
     }
     #[inline]
     fn fill_with<F>(&mut self, mut f: F)
     where
         F: FnMut() -> T,
     {
-        self.row_iter_mut().flatten().for_each(|e| *e = f());
+        self.iter_mut().for_each(|e| *e = f());
     }
 }
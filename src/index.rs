@@ -2,26 +2,40 @@ use crate::{
     slice::{Shape2D, Slice2D, Slice2DMut, SlicePtr, SlicePtrMut},
     utils::{calc_2d_index, calc_2d_range},
 };
-use core::ops::{
-    Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo,
-    RangeToInclusive,
+use core::{
+    iter::StepBy,
+    ops::{
+        Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+        RangeToInclusive,
+    },
 };
 
+/// # Safety
+/// `get_unchecked`, `get` and `index` must only return references to
+/// elements inside the shape of `slice`.
 pub unsafe trait Slice2DIndex<'a, T, S>
 where
     S: Shape2D + SlicePtr<T> + ?Sized,
 {
     type Ref: 'a;
+    /// # Safety
+    /// `self` must be in range of `slice`, i.e. `get` would return `Some`.
     unsafe fn get_unchecked(self, slice: &'a S) -> Self::Ref;
     fn get(self, slice: &'a S) -> Option<Self::Ref>;
     fn index(self, slice: &'a S) -> Self::Ref;
 }
 
+/// # Safety
+/// `get_unchecked_mut`, `get_mut` and `index_mut` must only return
+/// references to elements inside the shape of `slice`, and never two
+/// references to the same element.
 pub unsafe trait Slice2DIndexMut<'a, T, S>
 where
     S: Shape2D + SlicePtrMut<T> + ?Sized,
 {
     type RefMut: 'a;
+    /// # Safety
+    /// `self` must be in range of `slice`, i.e. `get_mut` would return `Some`.
     unsafe fn get_unchecked_mut(self, slice: &'a mut S) -> Self::RefMut;
     fn get_mut(self, slice: &'a mut S) -> Option<Self::RefMut>;
     fn index_mut(self, slice: &'a mut S) -> Self::RefMut;
@@ -39,7 +53,7 @@ where
     unsafe fn get_unchecked(self, slice: &S) -> Self::Ref {
         &*slice
             .get_slice_ptr()
-            .add(calc_2d_index(self.0, self.1, slice))
+            .offset(calc_2d_index(self.0, self.1, slice))
     }
 
     #[inline(always)]
@@ -69,7 +83,7 @@ where
     unsafe fn get_unchecked_mut(self, slice: &mut S) -> Self::RefMut {
         &mut *slice
             .get_slice_ptr_mut()
-            .add(calc_2d_index(self.0, self.1, slice))
+            .offset(calc_2d_index(self.0, self.1, slice))
     }
 
     #[inline(always)]
@@ -90,22 +104,78 @@ where
 }
 
 // index Range
-pub trait IRange: RangeBounds<usize> {}
-impl<'a> IRange for (Bound<&'a usize>, Bound<&'a usize>) {}
-impl IRange for (Bound<usize>, Bound<usize>) {}
-impl IRange for Range<&usize> {}
-impl IRange for Range<usize> {}
-impl IRange for RangeFrom<&usize> {}
-impl IRange for RangeFrom<usize> {}
-impl IRange for RangeInclusive<&usize> {}
-impl IRange for RangeInclusive<usize> {}
-impl IRange for RangeTo<&usize> {}
-impl IRange for RangeTo<usize> {}
-impl IRange for RangeToInclusive<&usize> {}
-impl IRange for RangeToInclusive<usize> {}
-impl IRange for RangeFull {}
-
-// (RangeBounds, RangeBounds)
+pub trait IRange {
+    // `(start, len, step)` of the indices selected out of `0..len`,
+    // `None` if any of them is out of range
+    fn calc_range(&self, len: usize) -> Option<(usize, usize, usize)>;
+}
+
+#[inline(always)]
+fn calc_bounds_range<B: RangeBounds<usize>>(
+    bound: &B,
+    len: usize,
+) -> Option<(usize, usize, usize)> {
+    let (start, end) = calc_2d_range(len, bound);
+    if start <= end && end <= len {
+        Some((start, end - start, 1))
+    } else {
+        None
+    }
+}
+
+macro_rules! impl_irange {
+    ($($t:ty),*) => {
+        $(
+            impl IRange for $t {
+                #[inline(always)]
+                fn calc_range(&self, len: usize) -> Option<(usize, usize, usize)> {
+                    calc_bounds_range(self, len)
+                }
+            }
+        )*
+    };
+}
+
+impl<'a> IRange for (Bound<&'a usize>, Bound<&'a usize>) {
+    #[inline(always)]
+    fn calc_range(&self, len: usize) -> Option<(usize, usize, usize)> {
+        calc_bounds_range(self, len)
+    }
+}
+impl_irange!(
+    (Bound<usize>, Bound<usize>),
+    Range<&usize>,
+    Range<usize>,
+    RangeFrom<&usize>,
+    RangeFrom<usize>,
+    RangeInclusive<&usize>,
+    RangeInclusive<usize>,
+    RangeTo<&usize>,
+    RangeTo<usize>,
+    RangeToInclusive<&usize>,
+    RangeToInclusive<usize>,
+    RangeFull
+);
+
+// `StepBy` does not expose its step, it is recovered from the first two items
+impl IRange for StepBy<Range<usize>> {
+    fn calc_range(&self, len: usize) -> Option<(usize, usize, usize)> {
+        let mut iter = self.clone();
+        let start = match iter.next() {
+            Some(start) => start,
+            None => return Some((0, 0, 1)),
+        };
+        let count = iter.len() + 1;
+        let step = iter.next().map_or(1, |second| second - start);
+        if start + (count - 1) * step < len {
+            Some((start, count, step))
+        } else {
+            None
+        }
+    }
+}
+
+// (IRange, IRange)
 unsafe impl<'a, T: 'a, S, B1, B2> Slice2DIndex<'a, T, S> for (B1, B2)
 where
     S: Shape2D + SlicePtr<T>,
@@ -115,26 +185,21 @@ where
     type Ref = Slice2D<'a, T>;
 
     unsafe fn get_unchecked(self, slice: &S) -> Self::Ref {
-        let (rs, re) = calc_2d_range(slice.get_row(), &self.0);
-        let (cs, ce) = calc_2d_range(slice.get_col(), &self.1);
-        Slice2D::<T>::from_raw_parts(
-            (rs, cs).get_unchecked(slice),
-            slice.get_base_col(),
-            re - rs,
-            ce - cs,
+        let (rs, rn, rstep) = self.0.calc_range(slice.get_row()).unwrap_unchecked();
+        let (cs, cn, cstep) = self.1.calc_range(slice.get_col()).unwrap_unchecked();
+        Slice2D::<T>::from_strided_raw_parts(
+            slice.get_slice_ptr().offset(calc_2d_index(rs, cs, slice)),
+            slice.get_row_stride() * rstep as isize,
+            slice.get_col_stride() * cstep as isize,
+            rn,
+            cn,
         )
     }
 
     fn get(self, slice: &'a S) -> Option<Self::Ref> {
-        let (rs, re) = calc_2d_range(slice.get_row(), &self.0);
-        let (cs, ce) = calc_2d_range(slice.get_col(), &self.1);
         unsafe {
-            if rs <= re
-                && rs <= slice.get_row()
-                && re <= slice.get_row()
-                && cs <= ce
-                && cs <= slice.get_col()
-                && ce <= slice.get_col()
+            if self.0.calc_range(slice.get_row()).is_some()
+                && self.1.calc_range(slice.get_col()).is_some()
             {
                 Some(self.get_unchecked(slice))
             } else {
@@ -157,26 +222,23 @@ where
     type RefMut = Slice2DMut<'a, T>;
 
     unsafe fn get_unchecked_mut(self, slice: &mut S) -> Self::RefMut {
-        let (rs, re) = calc_2d_range(slice.get_row(), &self.0);
-        let (cs, ce) = calc_2d_range(slice.get_col(), &self.1);
-        Slice2DMut::<T>::from_raw_parts(
-            (rs, cs).get_unchecked_mut(slice),
-            slice.get_base_col(),
-            re - rs,
-            ce - cs,
+        let (rs, rn, rstep) = self.0.calc_range(slice.get_row()).unwrap_unchecked();
+        let (cs, cn, cstep) = self.1.calc_range(slice.get_col()).unwrap_unchecked();
+        Slice2DMut::<T>::from_strided_raw_parts(
+            slice
+                .get_slice_ptr_mut()
+                .offset(calc_2d_index(rs, cs, slice)),
+            slice.get_row_stride() * rstep as isize,
+            slice.get_col_stride() * cstep as isize,
+            rn,
+            cn,
         )
     }
 
     fn get_mut(self, slice: &'a mut S) -> Option<Self::RefMut> {
-        let (rs, re) = calc_2d_range(slice.get_row(), &self.0);
-        let (cs, ce) = calc_2d_range(slice.get_col(), &self.1);
         unsafe {
-            if rs <= re
-                && rs <= slice.get_row()
-                && re <= slice.get_row()
-                && cs <= ce
-                && cs <= slice.get_col()
-                && ce <= slice.get_col()
+            if self.0.calc_range(slice.get_row()).is_some()
+                && self.1.calc_range(slice.get_col()).is_some()
             {
                 Some(self.get_unchecked_mut(slice))
             } else {
@@ -190,7 +252,7 @@ where
     }
 }
 
-// (RangeBounds, usize)
+// (IRange, usize)
 unsafe impl<'a, T: 'a, S, B> Slice2DIndex<'a, T, S> for (B, usize)
 where
     S: Shape2D + SlicePtr<T>,
@@ -198,24 +260,13 @@ where
 {
     type Ref = Slice2D<'a, T>;
 
-    unsafe fn get_unchecked(self, slice: &S) -> Self::Ref {
-        let (rs, re) = calc_2d_range(slice.get_row(), &self.0);
-        Slice2D::<T>::from_raw_parts(
-            (rs, self.1).get_unchecked(slice),
-            slice.get_base_col(),
-            re - rs,
-            1,
-        )
+    unsafe fn get_unchecked(self, slice: &'a S) -> Self::Ref {
+        (self.0, self.1..self.1 + 1).get_unchecked(slice)
     }
 
     fn get(self, slice: &'a S) -> Option<Self::Ref> {
-        let (rs, re) = calc_2d_range(slice.get_row(), &self.0);
         unsafe {
-            if rs <= re
-                && rs <= slice.get_row()
-                && re <= slice.get_row()
-                && self.1 < slice.get_col()
-            {
+            if self.0.calc_range(slice.get_row()).is_some() && self.1 < slice.get_col() {
                 Some(self.get_unchecked(slice))
             } else {
                 None
@@ -235,24 +286,13 @@ where
 {
     type RefMut = Slice2DMut<'a, T>;
 
-    unsafe fn get_unchecked_mut(self, slice: &mut S) -> Self::RefMut {
-        let (rs, re) = calc_2d_range(slice.get_row(), &self.0);
-        Slice2DMut::<T>::from_raw_parts(
-            (rs, self.1).get_unchecked_mut(slice),
-            slice.get_base_col(),
-            re - rs,
-            1,
-        )
+    unsafe fn get_unchecked_mut(self, slice: &'a mut S) -> Self::RefMut {
+        (self.0, self.1..self.1 + 1).get_unchecked_mut(slice)
     }
 
     fn get_mut(self, slice: &'a mut S) -> Option<Self::RefMut> {
-        let (rs, re) = calc_2d_range(slice.get_row(), &self.0);
         unsafe {
-            if rs <= re
-                && rs <= slice.get_row()
-                && re <= slice.get_row()
-                && self.1 < slice.get_col()
-            {
+            if self.0.calc_range(slice.get_row()).is_some() && self.1 < slice.get_col() {
                 Some(self.get_unchecked_mut(slice))
             } else {
                 None
@@ -265,7 +305,7 @@ where
     }
 }
 
-// (usize, RangeBounds)
+// (usize, IRange)
 unsafe impl<'a, T: 'a, S, B> Slice2DIndex<'a, T, S> for (usize, B)
 where
     S: Shape2D + SlicePtr<T>,
@@ -274,23 +314,12 @@ where
     type Ref = Slice2D<'a, T>;
 
     unsafe fn get_unchecked(self, slice: &'a S) -> Self::Ref {
-        let (cs, ce) = calc_2d_range(slice.get_col(), &self.1);
-        Slice2D::<T>::from_raw_parts(
-            (self.0, cs).get_unchecked(slice),
-            slice.get_base_col(),
-            1,
-            ce - cs,
-        )
+        (self.0..self.0 + 1, self.1).get_unchecked(slice)
     }
 
     fn get(self, slice: &'a S) -> Option<Self::Ref> {
-        let (cs, ce) = calc_2d_range(slice.get_col(), &self.1);
         unsafe {
-            if self.0 < slice.get_row()
-                && cs <= ce
-                && cs <= slice.get_col()
-                && ce <= slice.get_col()
-            {
+            if self.0 < slice.get_row() && self.1.calc_range(slice.get_col()).is_some() {
                 Some(self.get_unchecked(slice))
             } else {
                 None
@@ -310,24 +339,13 @@ where
 {
     type RefMut = Slice2DMut<'a, T>;
 
-    unsafe fn get_unchecked_mut(self, slice: &mut S) -> Self::RefMut {
-        let (cs, ce) = calc_2d_range(slice.get_col(), &self.1);
-        Slice2DMut::<T>::from_raw_parts(
-            (self.0, cs).get_unchecked_mut(slice),
-            slice.get_base_col(),
-            1,
-            ce - cs,
-        )
+    unsafe fn get_unchecked_mut(self, slice: &'a mut S) -> Self::RefMut {
+        (self.0..self.0 + 1, self.1).get_unchecked_mut(slice)
     }
 
     fn get_mut(self, slice: &'a mut S) -> Option<Self::RefMut> {
-        let (cs, ce) = calc_2d_range(slice.get_col(), &self.1);
         unsafe {
-            if self.0 < slice.get_row()
-                && cs <= ce
-                && cs <= slice.get_col()
-                && ce <= slice.get_col()
-            {
+            if self.0 < slice.get_row() && self.1.calc_range(slice.get_col()).is_some() {
                 Some(self.get_unchecked_mut(slice))
             } else {
                 None
@@ -345,6 +363,8 @@ pub trait GetElemRef<'a, T>: Shape2D + SlicePtr<T> {
     fn get<I>(&'a self, index: I) -> Option<I::Ref>
     where
         I: Slice2DIndex<'a, T, Self>;
+    /// # Safety
+    /// `index` must be in range, i.e. `get` would return `Some`.
     unsafe fn get_unchecked<I>(&'a self, index: I) -> I::Ref
     where
         I: Slice2DIndex<'a, T, Self>;
@@ -354,6 +374,8 @@ pub trait GetElemRefMut<'a, T>: Shape2D + SlicePtrMut<T> {
     fn get_mut<I>(&'a mut self, index: I) -> Option<I::RefMut>
     where
         I: Slice2DIndexMut<'a, T, Self>;
+    /// # Safety
+    /// `index` must be in range, i.e. `get_mut` would return `Some`.
     unsafe fn get_unchecked_mut<I>(&'a mut self, index: I) -> I::RefMut
     where
         I: Slice2DIndexMut<'a, T, Self>;
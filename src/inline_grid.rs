@@ -0,0 +1,360 @@
+use crate::{
+    cmp::slice_2d_eq,
+    index::{GetElemRef, Slice2DIndex, Slice2DIndexMut},
+    iter::Slice2DIter,
+    slice::{Shape2D, Shape2DExt, Slice2D, Slice2DMut, SlicePtr, SlicePtrMut},
+};
+use core::{
+    convert::From,
+    fmt,
+    hash::{Hash, Hasher},
+    mem::{self, MaybeUninit},
+    ops::{Index, IndexMut},
+    ptr, slice,
+};
+
+// Elements are stored contiguously in row-major order, the first
+// `row * col` cells are initialized.
+pub struct InlineGrid<T, const CAP: usize> {
+    data: [MaybeUninit<T>; CAP],
+
+    row: usize,
+    col: usize,
+}
+
+impl<T, const CAP: usize> InlineGrid<T, CAP> {
+    #[inline]
+    pub const fn new() -> InlineGrid<T, CAP> {
+        InlineGrid {
+            data: [const { MaybeUninit::uninit() }; CAP],
+            row: 0,
+            col: 0,
+        }
+    }
+    pub fn from_elem(row: usize, col: usize, elem: T) -> InlineGrid<T, CAP>
+    where
+        T: Clone,
+    {
+        InlineGrid::from_fn(row, col, |_| elem.clone())
+    }
+    pub fn from_fn<F>(row: usize, col: usize, f: F) -> InlineGrid<T, CAP>
+    where
+        F: FnMut((usize, usize)) -> T,
+    {
+        assert_fits::<CAP>(row, col);
+        let mut g = InlineGrid::new();
+        g.col = col;
+        g.extend_rows_with(row, f);
+        g
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr_at(0), self.row * self.col) }
+    }
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr_at_mut(0), self.row * self.col) }
+    }
+
+    pub fn resize(&mut self, row: usize, col: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.resize_with(row, col, || value.clone())
+    }
+    pub fn resize_with<F>(&mut self, row: usize, col: usize, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        assert_fits::<CAP>(row, col);
+        self.truncate_rows(row);
+        self.truncate_cols(col);
+        if col > self.col {
+            // spread the rows out from the last one, the new cells are
+            // filled while the rows are hidden, so a panic leaks them
+            let (old_col, n) = (self.col, mem::replace(&mut self.row, 0));
+            let p = self.ptr_at_mut(0);
+            for r in (1..n).rev() {
+                unsafe { ptr::copy(p.add(r * old_col), p.add(r * col), old_col) };
+            }
+            for r in 0..n {
+                for c in old_col..col {
+                    unsafe { ptr::write(p.add(r * col + c), f()) };
+                }
+            }
+            self.row = n;
+            self.col = col;
+        }
+        self.extend_rows_with(row - self.row, |_| f());
+    }
+    // append `n` rows, cells are written in row-major order
+    fn extend_rows_with<F>(&mut self, n: usize, mut f: F)
+    where
+        F: FnMut((usize, usize)) -> T,
+    {
+        for _ in 0..n {
+            let r = self.row;
+            for c in 0..self.col {
+                // elements of an unfinished row are leaked on panic
+                unsafe { ptr::write(self.ptr_at_mut(r * self.col + c), f((r, c))) };
+            }
+            self.row += 1;
+        }
+    }
+    // pointers to the `i`th cell, writers take theirs from a mutable borrow
+    #[inline(always)]
+    fn ptr_at(&self, i: usize) -> *const T {
+        (self.data.as_ptr() as *const T).wrapping_add(i)
+    }
+    #[inline(always)]
+    fn ptr_at_mut(&mut self, i: usize) -> *mut T {
+        (self.data.as_mut_ptr() as *mut T).wrapping_add(i)
+    }
+
+    pub fn push_row(&mut self, row: &[T])
+    where
+        T: Clone,
+    {
+        if self.row == 0 {
+            self.col = row.len();
+        }
+        assert!(row.len() == self.col, "row length does not match.");
+        assert_fits::<CAP>(self.row + 1, self.col);
+        self.extend_rows_with(1, |(_, c)| row[c].clone());
+    }
+    pub fn push_col<I>(&mut self, col: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut col = col.into_iter();
+        if self.row == 0 && self.col == 0 {
+            // the column decides the number of rows
+            self.col = 1;
+            for e in col {
+                assert!(self.row < CAP, "capacity exceeded.");
+                unsafe { ptr::write(self.ptr_at_mut(self.row), e) };
+                self.row += 1;
+            }
+            return;
+        }
+
+        let (row, old_col) = self.get_shape();
+        let col_len = old_col + 1;
+        assert_fits::<CAP>(row, col_len);
+        // spread the rows out from the last one, the new cells are filled
+        // while the rows are hidden, so a panic leaks them
+        self.row = 0;
+        let p = self.ptr_at_mut(0);
+        for r in (1..row).rev() {
+            unsafe { ptr::copy(p.add(r * old_col), p.add(r * col_len), old_col) };
+        }
+        let mut n = 0;
+        for e in col.by_ref().take(row) {
+            unsafe { ptr::write(p.add(n * col_len + old_col), e) };
+            n += 1;
+        }
+        if n < row || col.next().is_some() {
+            // drop the new cells and pack the rows again
+            for r in 0..n {
+                unsafe { ptr::drop_in_place(p.add(r * col_len + old_col)) };
+            }
+            for r in 1..row {
+                unsafe { ptr::copy(p.add(r * col_len), p.add(r * old_col), old_col) };
+            }
+            self.row = row;
+            panic!("column length does not match.");
+        }
+        self.row = row;
+        self.col = col_len;
+    }
+    pub fn pop_row(&mut self) -> Option<InlineGrid<T, CAP>> {
+        if self.row == 0 {
+            return None;
+        }
+        self.row -= 1;
+        let mut g = InlineGrid::new();
+        unsafe {
+            ptr::copy_nonoverlapping(self.ptr_at(self.row * self.col), g.ptr_at_mut(0), self.col)
+        };
+        g.row = 1;
+        g.col = self.col;
+        Some(g)
+    }
+    pub fn pop_col(&mut self) -> Option<InlineGrid<T, CAP>> {
+        if self.col == 0 {
+            return None;
+        }
+        let (row, col) = self.get_shape();
+        let mut g = InlineGrid::new();
+        let p = self.ptr_at_mut(0);
+        for r in 0..row {
+            unsafe {
+                ptr::copy_nonoverlapping(p.add(r * col + col - 1), g.ptr_at_mut(r), 1);
+                ptr::copy(p.add(r * col), p.add(r * (col - 1)), col - 1);
+            }
+        }
+        g.row = row;
+        g.col = 1;
+        self.col -= 1;
+        Some(g)
+    }
+    pub fn truncate_rows(&mut self, row: usize) {
+        if row < self.row {
+            // shrink first, so that a panicking destructor leaks the rest
+            // instead of dropping them twice
+            let old_row = mem::replace(&mut self.row, row);
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    self.ptr_at_mut(row * self.col),
+                    (old_row - row) * self.col,
+                ))
+            };
+        }
+    }
+    pub fn truncate_cols(&mut self, col: usize) {
+        if col < self.col {
+            let old_col = self.col;
+            let n = mem::replace(&mut self.row, 0);
+            let p = self.ptr_at_mut(0);
+            for r in 0..n {
+                unsafe {
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                        p.add(r * old_col + col),
+                        old_col - col,
+                    ));
+                    ptr::copy(p.add(r * old_col), p.add(r * col), col);
+                }
+            }
+            self.row = n;
+            self.col = col;
+        }
+    }
+    pub fn clear(&mut self) {
+        self.truncate_rows(0);
+    }
+
+    #[inline]
+    pub fn as_slice_2d(&self) -> Slice2D<'_, T> {
+        unsafe { Slice2D::from_raw_parts(self.ptr_at(0), self.col, self.row, self.col) }
+    }
+    #[inline]
+    pub fn as_slice_2d_mut(&mut self) -> Slice2DMut<'_, T> {
+        unsafe { Slice2DMut::from_raw_parts(self.ptr_at_mut(0), self.col, self.row, self.col) }
+    }
+}
+
+#[inline]
+fn assert_fits<const CAP: usize>(row: usize, col: usize) {
+    assert!(
+        row.checked_mul(col).is_some_and(|len| len <= CAP),
+        "capacity exceeded."
+    );
+}
+
+impl<T, const CAP: usize> Drop for InlineGrid<T, CAP> {
+    fn drop(&mut self) {
+        self.truncate_rows(0);
+    }
+}
+
+impl<T, const CAP: usize> Default for InlineGrid<T, CAP> {
+    fn default() -> Self {
+        InlineGrid::new()
+    }
+}
+
+impl<T: Clone, const CAP: usize> Clone for InlineGrid<T, CAP> {
+    fn clone(&self) -> Self {
+        InlineGrid::from_fn(self.row, self.col, |idx| unsafe {
+            self.get_unchecked(idx).clone()
+        })
+    }
+}
+
+impl<T: Hash, const CAP: usize> Hash for InlineGrid<T, CAP> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get_shape().hash(state);
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T: fmt::Debug, const CAP: usize> fmt::Debug for InlineGrid<T, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.row_slice_iter()).finish()
+    }
+}
+
+impl<T, const CAP: usize> Shape2D for InlineGrid<T, CAP> {
+    #[inline(always)]
+    fn get_row_stride(&self) -> isize {
+        self.col as isize
+    }
+
+    #[inline(always)]
+    fn get_col_stride(&self) -> isize {
+        1
+    }
+
+    #[inline(always)]
+    fn get_row(&self) -> usize {
+        self.row
+    }
+
+    #[inline(always)]
+    fn get_col(&self) -> usize {
+        self.col
+    }
+}
+impl<T, const CAP: usize> SlicePtr<T> for InlineGrid<T, CAP> {
+    fn get_slice_ptr(&self) -> *const T {
+        self.ptr_at(0)
+    }
+}
+impl<T, const CAP: usize> SlicePtrMut<T> for InlineGrid<T, CAP> {
+    fn get_slice_ptr_mut(&mut self) -> *mut T {
+        self.ptr_at_mut(0)
+    }
+}
+
+impl<'a, T, const CAP: usize> From<&'a InlineGrid<T, CAP>> for Slice2D<'a, T> {
+    fn from(g: &'a InlineGrid<T, CAP>) -> Self {
+        g.as_slice_2d()
+    }
+}
+impl<'a, T, const CAP: usize> From<&'a mut InlineGrid<T, CAP>> for Slice2DMut<'a, T> {
+    fn from(g: &'a mut InlineGrid<T, CAP>) -> Self {
+        g.as_slice_2d_mut()
+    }
+}
+
+impl<T, const CAP: usize> Index<(usize, usize)> for InlineGrid<T, CAP> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: (usize, usize)) -> &T {
+        Slice2DIndex::index(index, self)
+    }
+}
+
+impl<T, const CAP: usize> IndexMut<(usize, usize)> for InlineGrid<T, CAP> {
+    #[inline]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        Slice2DIndexMut::index_mut(index, self)
+    }
+}
+
+impl<T, A, const CAP: usize> PartialEq<A> for InlineGrid<T, CAP>
+where
+    T: PartialEq,
+    A: Shape2D + SlicePtr<T> + Slice2DIter<T, A>,
+{
+    fn eq(&self, other: &A) -> bool {
+        slice_2d_eq(self, other)
+    }
+}
+impl<T: Eq, const CAP: usize> Eq for InlineGrid<T, CAP> {}
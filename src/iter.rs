@@ -1,424 +1,1065 @@
 use crate::{
-    index::{GetElemRef, GetElemRefMut},
-    slice::{Shape2D, SlicePtr, SlicePtrMut},
-    utils::calc_2d_index,
+    slice::{Shape2D, Slice2D, Slice2DMut, Slice2DRaw, Slice2DRawRef, SlicePtr, SlicePtrMut},
+    strided::{StridedSlice, StridedSliceMut},
+    utils::{calc_2d_index, is_col_contiguous, is_contiguous, is_row_contiguous},
+};
+use core::{
+    iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterator},
+    marker::PhantomData,
+    slice,
 };
-use core::{iter::Iterator, marker::PhantomData, slice};
 
-// immutable variants
-pub type Row<'a, T> = slice::Iter<'a, T>;
+// `front..back` are the rows or columns left, taken from both ends
+macro_rules! impl_outer_iter {
+    ($t:ident, $item:ty) => {
+        impl<'a, T> Iterator for $t<'a, T> {
+            type Item = $item;
 
-pub struct Rows<'a, T, S>
-where
-    S: Shape2D + SlicePtr<T>,
-{
-    slice_2d: *const S,
-    row: usize,
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.front < self.back {
+                    let i = self.front;
+                    self.front += 1;
+                    unsafe { Some(self.get_unchecked(i)) }
+                } else {
+                    None
+                }
+            }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.back - self.front;
+                (len, Some(len))
+            }
+            fn count(self) -> usize {
+                self.back - self.front
+            }
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.front = self.front.saturating_add(n).min(self.back);
+                self.next()
+            }
+            fn last(mut self) -> Option<Self::Item> {
+                self.next_back()
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $t<'a, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.front < self.back {
+                    self.back -= 1;
+                    unsafe { Some(self.get_unchecked(self.back)) }
+                } else {
+                    None
+                }
+            }
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                self.back = self.back.saturating_sub(n).max(self.front);
+                self.next_back()
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for $t<'a, T> {}
+        impl<'a, T> FusedIterator for $t<'a, T> {}
+    };
+}
+
+// immutable variants
+pub struct Row<'a, T> {
+    ptr: *const T,
+    len: usize,
+    step: isize,
     _marker: PhantomData<&'a T>,
 }
 
-impl<'a, T, S> Rows<'a, T, S>
-where
-    S: Shape2D + SlicePtr<T>,
-{
+impl<'a, T> Row<'a, T> {
     #[inline]
-    pub fn new(slice_2d: &S) -> Rows<'_, T, S> {
-        Rows {
-            slice_2d,
-            row: 0,
+    pub fn new<S>(slice_2d: &S, row: usize) -> Option<Row<'_, T>>
+    where
+        S: Shape2D + SlicePtr<T>,
+    {
+        if row < slice_2d.get_row() {
+            unsafe { Some(Row::new_unchecked(slice_2d, row)) }
+        } else {
+            None
+        }
+    }
+    /// # Safety
+    /// `row` must be less than the number of rows of `slice_2d`.
+    #[inline]
+    pub unsafe fn new_unchecked<S>(slice_2d: &S, row: usize) -> Row<'_, T>
+    where
+        S: Shape2D + SlicePtr<T>,
+    {
+        Row {
+            ptr: slice_2d
+                .get_slice_ptr()
+                .offset(calc_2d_index(row, 0, slice_2d)),
+            len: slice_2d.get_col(),
+            step: slice_2d.get_col_stride(),
             _marker: PhantomData,
         }
     }
 }
 
-impl<'a, T, S> Iterator for Rows<'a, T, S>
-where
-    S: Shape2D + SlicePtr<T>,
-{
-    type Item = Row<'a, T>;
+impl<'a, T> Iterator for Row<'a, T> {
+    type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let slice_2d = unsafe { &*self.slice_2d };
-        if self.row < slice_2d.get_row() {
-            unsafe {
-                let row_ptr = slice_2d
-                    .get_slice_ptr()
-                    .add(calc_2d_index(self.row, 0, slice_2d));
-                let row_slice = slice::from_raw_parts(row_ptr, slice_2d.get_col());
-                self.row += 1;
-                Some(row_slice.iter())
+        unsafe {
+            if self.len > 0 {
+                let elem = &*self.ptr;
+                self.len -= 1;
+                self.ptr = self.ptr.wrapping_offset(self.step);
+                Some(elem)
+            } else {
+                None
             }
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+    fn count(self) -> usize {
+        self.len
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let n = n.min(self.len);
+        self.len -= n;
+        self.ptr = self.ptr.wrapping_offset(n as isize * self.step);
+        self.next()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Row<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len > 0 {
+            self.len -= 1;
+            unsafe { Some(&*self.ptr.offset(self.len as isize * self.step)) }
         } else {
             None
         }
     }
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.len -= n.min(self.len);
+        self.next_back()
+    }
 }
 
-pub struct RowSlices<'a, T, S>
-where
-    S: Shape2D + SlicePtr<T>,
-{
-    slice_2d: *const S,
-    row: usize,
+impl<'a, T> ExactSizeIterator for Row<'a, T> {}
+impl<'a, T> FusedIterator for Row<'a, T> {}
+
+pub struct Rows<'a, T> {
+    raw: Slice2DRaw<T>,
+    front: usize,
+    back: usize,
     _marker: PhantomData<&'a T>,
 }
 
-impl<'a, T, S> RowSlices<'a, T, S>
-where
-    S: Shape2D + SlicePtr<T>,
-{
+impl<'a, T> Rows<'a, T> {
     #[inline]
-    pub fn new(slice_2d: &S) -> RowSlices<'_, T, S> {
+    pub fn new<S>(slice_2d: &S) -> Rows<'_, T>
+    where
+        S: Shape2D + SlicePtr<T>,
+    {
+        unsafe { Rows::from_slice_2d_raw(Slice2DRaw::from_shape(slice_2d)) }
+    }
+    pub(crate) unsafe fn from_slice_2d_raw<'b>(raw: Slice2DRaw<T>) -> Rows<'b, T> {
+        Rows {
+            front: 0,
+            back: raw.get_row(),
+            raw,
+            _marker: PhantomData,
+        }
+    }
+    // the `i`th item
+    #[inline(always)]
+    unsafe fn get_unchecked(&mut self, i: usize) -> Row<'a, T> {
+        Row {
+            ptr: self.raw.slice.offset(calc_2d_index(i, 0, &self.raw)),
+            len: self.raw.get_col(),
+            step: self.raw.get_col_stride(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl_outer_iter!(Rows, Row<'a, T>);
+
+pub struct RowSlices<'a, T> {
+    raw: Slice2DRaw<T>,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> RowSlices<'a, T> {
+    #[inline]
+    pub fn new<S>(slice_2d: &S) -> RowSlices<'_, T>
+    where
+        S: Shape2D + SlicePtr<T>,
+    {
+        unsafe { RowSlices::from_slice_2d_raw(Slice2DRaw::from_shape(slice_2d)) }
+    }
+    pub(crate) unsafe fn from_slice_2d_raw<'b>(raw: Slice2DRaw<T>) -> RowSlices<'b, T> {
+        assert!(is_row_contiguous(&raw), "rows are not contiguous");
         RowSlices {
-            slice_2d,
-            row: 0,
+            front: 0,
+            back: raw.get_row(),
+            raw,
             _marker: PhantomData,
         }
     }
+    // the `i`th item
+    #[inline(always)]
+    unsafe fn get_unchecked(&mut self, i: usize) -> &'a [T] {
+        let ptr = self.raw.slice.offset(calc_2d_index(i, 0, &self.raw));
+        slice::from_raw_parts(ptr, self.raw.get_col())
+    }
 }
 
-impl<'a, T, S> Iterator for RowSlices<'a, T, S>
-where
-    S: Shape2D + SlicePtr<T>,
-{
-    type Item = &'a [T];
+impl_outer_iter!(RowSlices, &'a [T]);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let slice_2d = unsafe { &*self.slice_2d };
-        if self.row < slice_2d.get_row() {
-            unsafe {
-                let row_ptr = slice_2d
-                    .get_slice_ptr()
-                    .add(calc_2d_index(self.row, 0, slice_2d));
-                let row_slice = slice::from_raw_parts(row_ptr, slice_2d.get_col());
-                self.row += 1;
-                Some(row_slice)
-            }
-        } else {
-            None
+pub struct Cols<'a, T> {
+    raw: Slice2DRaw<T>,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Cols<'a, T> {
+    #[inline]
+    pub fn new<S>(slice_2d: &S) -> Cols<'_, T>
+    where
+        S: Shape2D + SlicePtr<T>,
+    {
+        unsafe { Cols::from_slice_2d_raw(Slice2DRaw::from_shape(slice_2d)) }
+    }
+    pub(crate) unsafe fn from_slice_2d_raw<'b>(raw: Slice2DRaw<T>) -> Cols<'b, T> {
+        Cols {
+            front: 0,
+            back: raw.get_col(),
+            raw,
+            _marker: PhantomData,
         }
     }
+    // the `i`th item
+    #[inline(always)]
+    unsafe fn get_unchecked(&mut self, i: usize) -> StridedSlice<'a, T> {
+        StridedSlice::from_raw_parts(
+            self.raw.slice.offset(calc_2d_index(0, i, &self.raw)),
+            self.raw.get_row(),
+            self.raw.get_row_stride(),
+        )
+    }
 }
 
-pub struct Col<'a, T> {
-    ptr: *const T,
-    end: *const T,
-    step: usize,
+impl_outer_iter!(Cols, StridedSlice<'a, T>);
+
+pub struct ColSlices<'a, T> {
+    raw: Slice2DRaw<T>,
+    front: usize,
+    back: usize,
     _marker: PhantomData<&'a T>,
 }
 
-impl<'a, T> Col<'a, T> {
+impl<'a, T> ColSlices<'a, T> {
     #[inline]
-    pub fn new<S>(slice_2d: &S, col: usize) -> Option<Col<'_, T>>
+    pub fn new<S>(slice_2d: &S) -> ColSlices<'_, T>
     where
         S: Shape2D + SlicePtr<T>,
     {
-        if col < slice_2d.get_col() {
-            unsafe { Some(Col::new_unchecked(slice_2d, col)) }
+        unsafe { ColSlices::from_slice_2d_raw(Slice2DRaw::from_shape(slice_2d)) }
+    }
+    pub(crate) unsafe fn from_slice_2d_raw<'b>(raw: Slice2DRaw<T>) -> ColSlices<'b, T> {
+        assert!(is_col_contiguous(&raw), "columns are not contiguous");
+        ColSlices {
+            front: 0,
+            back: raw.get_col(),
+            raw,
+            _marker: PhantomData,
+        }
+    }
+    // the `i`th item
+    #[inline(always)]
+    unsafe fn get_unchecked(&mut self, i: usize) -> &'a [T] {
+        let ptr = self.raw.slice.offset(calc_2d_index(0, i, &self.raw));
+        slice::from_raw_parts(ptr, self.raw.get_row())
+    }
+}
+
+impl_outer_iter!(ColSlices, &'a [T]);
+
+// mutable variants
+pub struct RowMut<'a, T> {
+    ptr: *mut T,
+    len: usize,
+    step: isize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> RowMut<'a, T> {
+    #[inline]
+    pub fn new<S>(slice_2d: &mut S, row: usize) -> Option<RowMut<'_, T>>
+    where
+        S: Shape2D + SlicePtrMut<T>,
+    {
+        if row < slice_2d.get_row() {
+            unsafe { Some(RowMut::new_unchecked(slice_2d, row)) }
         } else {
             None
         }
     }
+    /// # Safety
+    /// `row` must be less than the number of rows of `slice_2d`.
     #[inline]
-    pub unsafe fn new_unchecked<S>(slice_2d: &S, col: usize) -> Col<'_, T>
+    pub unsafe fn new_unchecked<S>(slice_2d: &mut S, row: usize) -> RowMut<'_, T>
     where
-        S: Shape2D + SlicePtr<T>,
+        S: Shape2D + SlicePtrMut<T>,
     {
-        let ptr = slice_2d.get_unchecked((0, col)) as *const T;
-        Col {
-            ptr,
-            end: ptr.add(slice_2d.get_base_col() * slice_2d.get_row()),
-            step: slice_2d.get_base_col(),
+        RowMut {
+            ptr: slice_2d
+                .get_slice_ptr_mut()
+                .offset(calc_2d_index(row, 0, slice_2d)),
+            len: slice_2d.get_col(),
+            step: slice_2d.get_col_stride(),
             _marker: PhantomData,
         }
     }
 }
 
-impl<'a, T> Iterator for Col<'a, T> {
-    type Item = &'a T;
+impl<'a, T> Iterator for RowMut<'a, T> {
+    type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
         unsafe {
-            if self.ptr < self.end {
-                let elem = &*self.ptr;
-                self.ptr = self.ptr.add(self.step);
+            if self.len > 0 {
+                let elem = &mut *self.ptr;
+                self.len -= 1;
+                self.ptr = self.ptr.wrapping_offset(self.step);
                 Some(elem)
             } else {
                 None
             }
         }
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+    fn count(self) -> usize {
+        self.len
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let n = n.min(self.len);
+        self.len -= n;
+        self.ptr = self.ptr.wrapping_offset(n as isize * self.step);
+        self.next()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
 }
 
-pub struct Cols<'a, T, S>
-where
-    S: Shape2D + SlicePtr<T>,
-{
-    slice_2d: *const S,
-    col: usize,
-    _marker: PhantomData<&'a T>,
+impl<'a, T> DoubleEndedIterator for RowMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len > 0 {
+            self.len -= 1;
+            unsafe { Some(&mut *self.ptr.offset(self.len as isize * self.step)) }
+        } else {
+            None
+        }
+    }
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.len -= n.min(self.len);
+        self.next_back()
+    }
 }
 
-impl<'a, T, S> Cols<'a, T, S>
-where
-    S: Shape2D + SlicePtr<T>,
-{
+impl<'a, T> ExactSizeIterator for RowMut<'a, T> {}
+impl<'a, T> FusedIterator for RowMut<'a, T> {}
+
+pub struct RowsMut<'a, T> {
+    raw: Slice2DRaw<T>,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> RowsMut<'a, T> {
     #[inline]
-    pub fn new(slice_2d: &S) -> Cols<'_, T, S> {
-        Cols {
-            slice_2d,
-            col: 0,
+    pub fn new<S>(slice_2d: &mut S) -> RowsMut<'_, T>
+    where
+        S: Shape2D + SlicePtrMut<T>,
+    {
+        unsafe { RowsMut::from_slice_2d_raw(Slice2DRaw::from_shape_mut(slice_2d)) }
+    }
+    pub(crate) unsafe fn from_slice_2d_raw<'b>(raw: Slice2DRaw<T>) -> RowsMut<'b, T> {
+        RowsMut {
+            front: 0,
+            back: raw.get_row(),
+            raw,
             _marker: PhantomData,
         }
     }
-}
-
-impl<'a, T, S: 'a> Iterator for Cols<'a, T, S>
-where
-    S: Shape2D + SlicePtr<T>,
-{
-    type Item = Col<'a, T>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let slice_2d = unsafe { &*self.slice_2d };
-        if self.col < slice_2d.get_col() {
-            let old_col = self.col;
-            self.col += 1;
-            unsafe { Some(Col::new_unchecked(slice_2d, old_col)) }
-        } else {
-            None
+    // the `i`th item, every item must be created at most once
+    #[inline(always)]
+    unsafe fn get_unchecked(&mut self, i: usize) -> RowMut<'a, T> {
+        RowMut {
+            ptr: self.raw.slice.offset(calc_2d_index(i, 0, &self.raw)) as *mut T,
+            len: self.raw.get_col(),
+            step: self.raw.get_col_stride(),
+            _marker: PhantomData,
         }
     }
 }
 
-// mutable variants
-pub type RowMut<'a, T> = slice::IterMut<'a, T>;
+impl_outer_iter!(RowsMut, RowMut<'a, T>);
 
-pub struct RowsMut<'a, T, S>
-where
-    S: Shape2D + SlicePtrMut<T>,
-{
-    slice_2d: *mut S,
-    row: usize,
+pub struct RowSlicesMut<'a, T> {
+    raw: Slice2DRaw<T>,
+    front: usize,
+    back: usize,
     _marker: PhantomData<&'a mut T>,
 }
 
-impl<'a, T, S> RowsMut<'a, T, S>
-where
-    S: Shape2D + SlicePtrMut<T>,
-{
+impl<'a, T> RowSlicesMut<'a, T> {
     #[inline]
-    pub fn new(slice_2d: &mut S) -> RowsMut<'_, T, S> {
-        RowsMut {
-            slice_2d,
-            row: 0,
+    pub fn new<S>(slice_2d: &mut S) -> RowSlicesMut<'_, T>
+    where
+        S: Shape2D + SlicePtrMut<T>,
+    {
+        unsafe { RowSlicesMut::from_slice_2d_raw(Slice2DRaw::from_shape_mut(slice_2d)) }
+    }
+    pub(crate) unsafe fn from_slice_2d_raw<'b>(raw: Slice2DRaw<T>) -> RowSlicesMut<'b, T> {
+        assert!(is_row_contiguous(&raw), "rows are not contiguous");
+        RowSlicesMut {
+            front: 0,
+            back: raw.get_row(),
+            raw,
             _marker: PhantomData,
         }
     }
+    // the `i`th item, every item must be created at most once
+    #[inline(always)]
+    unsafe fn get_unchecked(&mut self, i: usize) -> &'a mut [T] {
+        let ptr = self.raw.slice.offset(calc_2d_index(i, 0, &self.raw));
+        slice::from_raw_parts_mut(ptr as *mut T, self.raw.get_col())
+    }
 }
 
-impl<'a, T, S> Iterator for RowsMut<'a, T, S>
-where
-    S: Shape2D + SlicePtrMut<T>,
-{
-    type Item = RowMut<'a, T>;
+impl_outer_iter!(RowSlicesMut, &'a mut [T]);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let slice_2d = unsafe { &mut *self.slice_2d };
-        if self.row < slice_2d.get_row() {
-            let row_ptr = unsafe { slice_2d.get_unchecked_mut((self.row, 0)) } as *mut T;
-            let row_slice = unsafe { slice::from_raw_parts_mut(row_ptr, slice_2d.get_col()) };
-            self.row += 1;
-            Some(row_slice.iter_mut())
-        } else {
-            None
+pub struct ColsMut<'a, T> {
+    raw: Slice2DRaw<T>,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> ColsMut<'a, T> {
+    #[inline]
+    pub fn new<S>(slice_2d: &mut S) -> ColsMut<'_, T>
+    where
+        S: Shape2D + SlicePtrMut<T>,
+    {
+        unsafe { ColsMut::from_slice_2d_raw(Slice2DRaw::from_shape_mut(slice_2d)) }
+    }
+    pub(crate) unsafe fn from_slice_2d_raw<'b>(raw: Slice2DRaw<T>) -> ColsMut<'b, T> {
+        ColsMut {
+            front: 0,
+            back: raw.get_col(),
+            raw,
+            _marker: PhantomData,
         }
     }
+    // the `i`th item, every item must be created at most once
+    #[inline(always)]
+    unsafe fn get_unchecked(&mut self, i: usize) -> StridedSliceMut<'a, T> {
+        StridedSliceMut::from_raw_parts(
+            self.raw.slice.offset(calc_2d_index(0, i, &self.raw)) as *mut T,
+            self.raw.get_row(),
+            self.raw.get_row_stride(),
+        )
+    }
 }
 
-pub struct RowSlicesMut<'a, T, S>
-where
-    S: Shape2D + SlicePtrMut<T>,
-{
-    slice_2d: *mut S,
-    row: usize,
+impl_outer_iter!(ColsMut, StridedSliceMut<'a, T>);
+
+pub struct ColSlicesMut<'a, T> {
+    raw: Slice2DRaw<T>,
+    front: usize,
+    back: usize,
     _marker: PhantomData<&'a mut T>,
 }
 
-impl<'a, T, S> RowSlicesMut<'a, T, S>
-where
-    S: Shape2D + SlicePtrMut<T>,
-{
+impl<'a, T> ColSlicesMut<'a, T> {
     #[inline]
-    pub fn new(slice_2d: &mut S) -> RowSlicesMut<'_, T, S> {
-        RowSlicesMut {
-            slice_2d,
-            row: 0,
+    pub fn new<S>(slice_2d: &mut S) -> ColSlicesMut<'_, T>
+    where
+        S: Shape2D + SlicePtrMut<T>,
+    {
+        unsafe { ColSlicesMut::from_slice_2d_raw(Slice2DRaw::from_shape_mut(slice_2d)) }
+    }
+    pub(crate) unsafe fn from_slice_2d_raw<'b>(raw: Slice2DRaw<T>) -> ColSlicesMut<'b, T> {
+        assert!(is_col_contiguous(&raw), "columns are not contiguous");
+        ColSlicesMut {
+            front: 0,
+            back: raw.get_col(),
+            raw,
             _marker: PhantomData,
         }
     }
+    // the `i`th item, every item must be created at most once
+    #[inline(always)]
+    unsafe fn get_unchecked(&mut self, i: usize) -> &'a mut [T] {
+        let ptr = self.raw.slice.offset(calc_2d_index(0, i, &self.raw));
+        slice::from_raw_parts_mut(ptr as *mut T, self.raw.get_row())
+    }
 }
 
-impl<'a, T, S> Iterator for RowSlicesMut<'a, T, S>
-where
-    S: Shape2D + SlicePtrMut<T>,
-{
-    type Item = &'a mut [T];
+impl_outer_iter!(ColSlicesMut, &'a mut [T]);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let slice_2d = unsafe { &mut *self.slice_2d };
-        if self.row < slice_2d.get_row() {
-            let row_ptr = unsafe { slice_2d.get_unchecked_mut((self.row, 0)) } as *mut T;
-            let row_slice = unsafe { slice::from_raw_parts_mut(row_ptr, slice_2d.get_col()) };
-            self.row += 1;
-            Some(row_slice)
+// iterators yielding the position of every element along with it
+// position of the elements left, in either row-major or column-major order
+pub(crate) struct IndexCursor {
+    // strides along the outer (slow) and the inner (fast) axis
+    outer_stride: isize,
+    inner_stride: isize,
+    inner_len: usize,
+    front: usize,
+    back: usize,
+    // `front` split along the axes
+    outer: usize,
+    inner: usize,
+    col_major: bool,
+}
+
+impl IndexCursor {
+    fn new<S: Shape2D>(slice_2d: &S, col_major: bool) -> IndexCursor {
+        IndexCursor::from_parts(
+            slice_2d.get_row(),
+            slice_2d.get_col(),
+            slice_2d.get_row_stride(),
+            slice_2d.get_col_stride(),
+            col_major,
+        )
+    }
+    pub(crate) fn from_parts(
+        row: usize,
+        col: usize,
+        row_stride: isize,
+        col_stride: isize,
+        col_major: bool,
+    ) -> IndexCursor {
+        let (outer_stride, inner_stride, inner_len) = if col_major {
+            (col_stride, row_stride, row)
+        } else {
+            (row_stride, col_stride, col)
+        };
+        IndexCursor {
+            outer_stride,
+            inner_stride,
+            inner_len,
+            front: 0,
+            back: row * col,
+            outer: 0,
+            inner: 0,
+            col_major,
+        }
+    }
+    #[inline(always)]
+    pub(crate) fn len(&self) -> usize {
+        self.back - self.front
+    }
+    // `(row, col)` and offset of `(outer, inner)`
+    #[inline(always)]
+    fn locate(&self, outer: usize, inner: usize) -> ((usize, usize), isize) {
+        let offset = outer as isize * self.outer_stride + inner as isize * self.inner_stride;
+        if self.col_major {
+            ((inner, outer), offset)
+        } else {
+            ((outer, inner), offset)
+        }
+    }
+    #[inline(always)]
+    pub(crate) fn next(&mut self) -> Option<((usize, usize), isize)> {
+        if self.front < self.back {
+            let item = self.locate(self.outer, self.inner);
+            self.front += 1;
+            self.inner += 1;
+            if self.inner == self.inner_len {
+                self.inner = 0;
+                self.outer += 1;
+            }
+            Some(item)
         } else {
             None
         }
     }
+    #[inline(always)]
+    pub(crate) fn next_back(&mut self) -> Option<((usize, usize), isize)> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.locate(self.back / self.inner_len, self.back % self.inner_len))
+        } else {
+            None
+        }
+    }
+    pub(crate) fn skip_front(&mut self, n: usize) {
+        self.front = self.front.saturating_add(n).min(self.back);
+        if let Some(outer) = self.front.checked_div(self.inner_len) {
+            self.outer = outer;
+            self.inner = self.front % self.inner_len;
+        }
+    }
+    pub(crate) fn skip_back(&mut self, n: usize) {
+        self.back = self.back.saturating_sub(n).max(self.front);
+    }
+    // offsets of the elements left, one inner line at a time
+    #[inline]
+    fn fold_offsets<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, isize) -> B,
+    {
+        let mut acc = init;
+        while self.front < self.back {
+            let n = (self.inner_len - self.inner).min(self.back - self.front);
+            let base = self.outer as isize * self.outer_stride;
+            for i in self.inner..self.inner + n {
+                acc = f(acc, base + i as isize * self.inner_stride);
+            }
+            self.front += n;
+            self.outer += 1;
+            self.inner = 0;
+        }
+        acc
+    }
 }
 
-pub struct ColMut<'a, T> {
-    ptr: *mut T,
-    end: *mut T,
-    step: usize,
+pub struct IndexedIter<'a, T> {
+    ptr: *const T,
+    cursor: IndexCursor,
     _marker: PhantomData<&'a T>,
 }
 
-impl<'a, T> ColMut<'a, T> {
+impl<'a, T> IndexedIter<'a, T> {
     #[inline]
-    pub fn new<S>(slice_2d: &mut S, col: usize) -> Option<ColMut<'_, T>>
+    pub fn new<S>(slice_2d: &S, col_major: bool) -> IndexedIter<'_, T>
     where
-        S: Shape2D + SlicePtrMut<T>,
+        S: Shape2D + SlicePtr<T>,
     {
-        if col < slice_2d.get_col() {
-            unsafe { Some(ColMut::new_unchecked(slice_2d, col)) }
-        } else {
-            None
+        IndexedIter {
+            ptr: slice_2d.get_slice_ptr(),
+            cursor: IndexCursor::new(slice_2d, col_major),
+            _marker: PhantomData,
         }
     }
+}
+
+pub struct IndexedIterMut<'a, T> {
+    ptr: *mut T,
+    cursor: IndexCursor,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> IndexedIterMut<'a, T> {
     #[inline]
-    pub unsafe fn new_unchecked<S>(slice_2d: &mut S, col: usize) -> ColMut<'_, T>
+    pub fn new<S>(slice_2d: &mut S, col_major: bool) -> IndexedIterMut<'_, T>
     where
         S: Shape2D + SlicePtrMut<T>,
     {
-        let ptr = slice_2d.get_unchecked_mut((0, col)) as *mut T;
-        ColMut {
-            ptr,
-            end: ptr.add(slice_2d.get_base_col() * slice_2d.get_row()),
-            step: slice_2d.get_base_col(),
+        IndexedIterMut {
+            ptr: slice_2d.get_slice_ptr_mut(),
+            cursor: IndexCursor::new(slice_2d, col_major),
             _marker: PhantomData,
         }
     }
 }
 
-impl<'a, T> Iterator for ColMut<'a, T> {
-    type Item = &'a mut T;
+macro_rules! impl_indexed_iter {
+    ($t:ident, $item:ty, $deref:expr) => {
+        impl<'a, T> Iterator for $t<'a, T> {
+            type Item = ((usize, usize), $item);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        unsafe {
-            if self.ptr < self.end {
-                let elem = &mut *self.ptr;
-                self.ptr = self.ptr.add(self.step);
-                Some(elem)
-            } else {
-                None
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let (idx, offset) = self.cursor.next()?;
+                unsafe { Some((idx, $deref(self.ptr.offset(offset)))) }
+            }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.cursor.len(), Some(self.cursor.len()))
+            }
+            fn count(self) -> usize {
+                self.cursor.len()
+            }
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.cursor.skip_front(n);
+                self.next()
+            }
+            fn last(mut self) -> Option<Self::Item> {
+                self.next_back()
             }
         }
-    }
+
+        impl<'a, T> DoubleEndedIterator for $t<'a, T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let (idx, offset) = self.cursor.next_back()?;
+                unsafe { Some((idx, $deref(self.ptr.offset(offset)))) }
+            }
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                self.cursor.skip_back(n);
+                self.next_back()
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for $t<'a, T> {}
+        impl<'a, T> FusedIterator for $t<'a, T> {}
+    };
 }
 
-pub struct ColsMut<'a, T, S>
-where
-    S: Shape2D + SlicePtrMut<T>,
-{
-    slice_2d: *mut S,
-    col: usize,
-    _marker: PhantomData<&'a mut T>,
+impl_indexed_iter!(IndexedIter, &'a T, |p: *const T| &*p);
+impl_indexed_iter!(IndexedIterMut, &'a mut T, |p: *mut T| &mut *p);
+
+// every element in row-major order, contiguous views are walked as one slice
+pub struct Iter<'a, T> {
+    inner: IterInner<'a, T>,
 }
 
-impl<'a, T, S> ColsMut<'a, T, S>
-where
-    S: Shape2D + SlicePtrMut<T>,
-{
+enum IterInner<'a, T> {
+    Contiguous(slice::Iter<'a, T>),
+    Strided { ptr: *const T, cursor: IndexCursor },
+}
+
+impl<'a, T> Iter<'a, T> {
     #[inline]
-    pub fn new(slice_2d: &mut S) -> ColsMut<'_, T, S> {
-        ColsMut {
-            slice_2d,
-            col: 0,
-            _marker: PhantomData,
-        }
+    pub fn new<S>(slice_2d: &S) -> Iter<'_, T>
+    where
+        S: Shape2D + SlicePtr<T>,
+    {
+        let inner = if is_contiguous(slice_2d) {
+            let len = slice_2d.get_row() * slice_2d.get_col();
+            let slice = if len == 0 {
+                &[]
+            } else {
+                unsafe { slice::from_raw_parts(slice_2d.get_slice_ptr(), len) }
+            };
+            IterInner::Contiguous(slice.iter())
+        } else {
+            IterInner::Strided {
+                ptr: slice_2d.get_slice_ptr(),
+                cursor: IndexCursor::new(slice_2d, false),
+            }
+        };
+        Iter { inner }
     }
 }
 
-impl<'a, T, S: 'a> Iterator for ColsMut<'a, T, S>
-where
-    S: Shape2D + SlicePtrMut<T>,
-{
-    type Item = ColMut<'a, T>;
+pub struct IterMut<'a, T> {
+    inner: IterMutInner<'a, T>,
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let slice_2d = unsafe { &mut *self.slice_2d };
-        if self.col < slice_2d.get_col() {
-            let old_col = self.col;
-            self.col += 1;
-            unsafe { Some(ColMut::new_unchecked(slice_2d, old_col)) }
+enum IterMutInner<'a, T> {
+    Contiguous(slice::IterMut<'a, T>),
+    Strided { ptr: *mut T, cursor: IndexCursor },
+}
+
+impl<'a, T> IterMut<'a, T> {
+    #[inline]
+    pub fn new<S>(slice_2d: &mut S) -> IterMut<'_, T>
+    where
+        S: Shape2D + SlicePtrMut<T>,
+    {
+        let inner = if is_contiguous(slice_2d) {
+            let len = slice_2d.get_row() * slice_2d.get_col();
+            let slice = if len == 0 {
+                &mut []
+            } else {
+                unsafe { slice::from_raw_parts_mut(slice_2d.get_slice_ptr_mut(), len) }
+            };
+            IterMutInner::Contiguous(slice.iter_mut())
         } else {
-            None
-        }
+            IterMutInner::Strided {
+                ptr: slice_2d.get_slice_ptr_mut(),
+                cursor: IndexCursor::new(slice_2d, false),
+            }
+        };
+        IterMut { inner }
     }
 }
 
+macro_rules! impl_flat_iter {
+    ($t:ident, $inner:ident, $item:ty, $deref:expr) => {
+        impl<'a, T> Iterator for $t<'a, T> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                match &mut self.inner {
+                    $inner::Contiguous(iter) => iter.next(),
+                    $inner::Strided { ptr, cursor } => {
+                        let (_, offset) = cursor.next()?;
+                        unsafe { Some($deref(ptr.offset(offset))) }
+                    }
+                }
+            }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = match &self.inner {
+                    $inner::Contiguous(iter) => iter.len(),
+                    $inner::Strided { cursor, .. } => cursor.len(),
+                };
+                (len, Some(len))
+            }
+            fn count(self) -> usize {
+                self.len()
+            }
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                match &mut self.inner {
+                    $inner::Contiguous(iter) => iter.nth(n),
+                    $inner::Strided { cursor, .. } => {
+                        cursor.skip_front(n);
+                        self.next()
+                    }
+                }
+            }
+            fn last(mut self) -> Option<Self::Item> {
+                self.next_back()
+            }
+            // `try_fold` can not be overridden on stable, it goes through `next`
+            #[inline]
+            fn fold<B, F>(self, init: B, mut f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B,
+            {
+                match self.inner {
+                    $inner::Contiguous(iter) => iter.fold(init, f),
+                    $inner::Strided { ptr, cursor } => cursor
+                        .fold_offsets(init, |acc, offset| unsafe {
+                            f(acc, $deref(ptr.offset(offset)))
+                        }),
+                }
+            }
+            #[inline]
+            fn for_each<F>(self, mut f: F)
+            where
+                F: FnMut(Self::Item),
+            {
+                match self.inner {
+                    $inner::Contiguous(iter) => iter.for_each(f),
+                    $inner::Strided { ptr, cursor } => cursor
+                        .fold_offsets((), |(), offset| unsafe { f($deref(ptr.offset(offset))) }),
+                }
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $t<'a, T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                match &mut self.inner {
+                    $inner::Contiguous(iter) => iter.next_back(),
+                    $inner::Strided { ptr, cursor } => {
+                        let (_, offset) = cursor.next_back()?;
+                        unsafe { Some($deref(ptr.offset(offset))) }
+                    }
+                }
+            }
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                match &mut self.inner {
+                    $inner::Contiguous(iter) => iter.nth_back(n),
+                    $inner::Strided { cursor, .. } => {
+                        cursor.skip_back(n);
+                        self.next_back()
+                    }
+                }
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for $t<'a, T> {}
+        impl<'a, T> FusedIterator for $t<'a, T> {}
+    };
+}
+
+impl_flat_iter!(Iter, IterInner, &'a T, |p: *const T| &*p);
+impl_flat_iter!(IterMut, IterMutInner, &'a mut T, |p: *mut T| &mut *p);
+
 // iterator related traits
 
 pub trait Slice2DIter<T, S>
 where
     S: Shape2D + SlicePtr<T>,
 {
-    fn row_iter(&self) -> Rows<'_, T, S>;
-    fn row_slice_iter(&self) -> RowSlices<'_, T, S>;
-    fn col_iter(&self) -> Cols<'_, T, S>;
+    fn row_iter(&self) -> Rows<'_, T>;
+    // the slice iterators panic unless the rows, or the columns, are
+    // contiguous, which row-major views always are for rows. the `try_`
+    // variants return `None` instead
+    fn row_slice_iter(&self) -> RowSlices<'_, T>;
+    fn try_row_slice_iter(&self) -> Option<RowSlices<'_, T>>;
+    fn col_iter(&self) -> Cols<'_, T>;
+    fn col_slice_iter(&self) -> ColSlices<'_, T>;
+    fn try_col_slice_iter(&self) -> Option<ColSlices<'_, T>>;
+    fn iter(&self) -> Iter<'_, T>;
+    fn indexed_iter(&self) -> IndexedIter<'_, T>;
+    fn indexed_col_major_iter(&self) -> IndexedIter<'_, T>;
 }
 
 pub trait Slice2DIterMut<T, S>
 where
     S: Shape2D + SlicePtrMut<T>,
 {
-    fn row_iter_mut(&mut self) -> RowsMut<'_, T, S>;
-    fn row_slice_iter_mut(&mut self) -> RowSlicesMut<'_, T, S>;
-    fn col_iter_mut(&mut self) -> ColsMut<'_, T, S>;
+    fn row_iter_mut(&mut self) -> RowsMut<'_, T>;
+    fn row_slice_iter_mut(&mut self) -> RowSlicesMut<'_, T>;
+    fn try_row_slice_iter_mut(&mut self) -> Option<RowSlicesMut<'_, T>>;
+    fn col_iter_mut(&mut self) -> ColsMut<'_, T>;
+    fn col_slice_iter_mut(&mut self) -> ColSlicesMut<'_, T>;
+    fn try_col_slice_iter_mut(&mut self) -> Option<ColSlicesMut<'_, T>>;
+    fn iter_mut(&mut self) -> IterMut<'_, T>;
+    fn indexed_iter_mut(&mut self) -> IndexedIterMut<'_, T>;
+    fn indexed_col_major_iter_mut(&mut self) -> IndexedIterMut<'_, T>;
 }
 
-impl<'a, T, S> Slice2DIter<T, S> for S
+impl<T, S> Slice2DIter<T, S> for S
 where
     S: Shape2D + SlicePtr<T>,
 {
-    fn row_iter(&self) -> Rows<'_, T, S> {
+    fn row_iter(&self) -> Rows<'_, T> {
         Rows::new(self)
     }
 
-    fn row_slice_iter(&self) -> RowSlices<'_, T, S> {
+    fn row_slice_iter(&self) -> RowSlices<'_, T> {
         RowSlices::new(self)
     }
 
-    fn col_iter(&self) -> Cols<'_, T, S> {
+    fn try_row_slice_iter(&self) -> Option<RowSlices<'_, T>> {
+        if is_row_contiguous(self) {
+            Some(RowSlices::new(self))
+        } else {
+            None
+        }
+    }
+
+    fn col_iter(&self) -> Cols<'_, T> {
         Cols::new(self)
     }
+
+    fn col_slice_iter(&self) -> ColSlices<'_, T> {
+        ColSlices::new(self)
+    }
+
+    fn try_col_slice_iter(&self) -> Option<ColSlices<'_, T>> {
+        if is_col_contiguous(self) {
+            Some(ColSlices::new(self))
+        } else {
+            None
+        }
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self)
+    }
+
+    fn indexed_iter(&self) -> IndexedIter<'_, T> {
+        IndexedIter::new(self, false)
+    }
+
+    fn indexed_col_major_iter(&self) -> IndexedIter<'_, T> {
+        IndexedIter::new(self, true)
+    }
 }
 
-impl<'a, T, S> Slice2DIterMut<T, S> for S
+impl<T, S> Slice2DIterMut<T, S> for S
 where
     S: Shape2D + SlicePtrMut<T>,
 {
-    fn row_iter_mut(&mut self) -> RowsMut<'_, T, S> {
+    fn row_iter_mut(&mut self) -> RowsMut<'_, T> {
         RowsMut::new(self)
     }
 
-    fn row_slice_iter_mut(&mut self) -> RowSlicesMut<'_, T, S> {
+    fn row_slice_iter_mut(&mut self) -> RowSlicesMut<'_, T> {
         RowSlicesMut::new(self)
     }
 
-    fn col_iter_mut(&mut self) -> ColsMut<'_, T, S> {
+    fn try_row_slice_iter_mut(&mut self) -> Option<RowSlicesMut<'_, T>> {
+        if is_row_contiguous(self) {
+            Some(RowSlicesMut::new(self))
+        } else {
+            None
+        }
+    }
+
+    fn col_iter_mut(&mut self) -> ColsMut<'_, T> {
         ColsMut::new(self)
     }
+
+    fn col_slice_iter_mut(&mut self) -> ColSlicesMut<'_, T> {
+        ColSlicesMut::new(self)
+    }
+
+    fn try_col_slice_iter_mut(&mut self) -> Option<ColSlicesMut<'_, T>> {
+        if is_col_contiguous(self) {
+            Some(ColSlicesMut::new(self))
+        } else {
+            None
+        }
+    }
+
+    fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self)
+    }
+
+    fn indexed_iter_mut(&mut self) -> IndexedIterMut<'_, T> {
+        IndexedIterMut::new(self, false)
+    }
+
+    fn indexed_col_major_iter_mut(&mut self) -> IndexedIterMut<'_, T> {
+        IndexedIterMut::new(self, true)
+    }
+}
+
+// a view iterates over its rows. they are strided `Row`s rather than row
+// slices, because transposed, stepped and column-major views have no
+// contiguous rows and `IntoIterator` cannot fail. `row_slice_iter` and
+// `try_row_slice_iter` hand out slices where the layout allows it
+
+impl<'a, T> IntoIterator for Slice2D<'a, T> {
+    type Item = Row<'a, T>;
+    type IntoIter = Rows<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        unsafe { Rows::from_slice_2d_raw(*self.get_slice_2d_raw()) }
+    }
+}
+
+impl<'b, 'a, T> IntoIterator for &'b Slice2D<'a, T> {
+    type Item = Row<'b, T>;
+    type IntoIter = Rows<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.row_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for Slice2DMut<'a, T> {
+    type Item = RowMut<'a, T>;
+    type IntoIter = RowsMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        unsafe { RowsMut::from_slice_2d_raw(*self.get_slice_2d_raw()) }
+    }
+}
+
+impl<'b, 'a, T> IntoIterator for &'b Slice2DMut<'a, T> {
+    type Item = Row<'b, T>;
+    type IntoIter = Rows<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.row_iter()
+    }
+}
+
+impl<'b, 'a, T> IntoIterator for &'b mut Slice2DMut<'a, T> {
+    type Item = RowMut<'b, T>;
+    type IntoIter = RowsMut<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.row_iter_mut()
+    }
 }
@@ -1,27 +1,57 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod slice;
 
 pub mod cmp;
+pub mod col_major;
+pub mod diag;
 pub mod fill;
 pub mod index;
 pub mod iter;
+pub mod map;
+pub mod padded;
 pub mod split;
+pub mod stack;
+pub mod strided;
 pub mod swap;
 pub mod utils;
+pub mod windows;
+pub mod wrapping;
 
+#[cfg(feature = "const_generics")]
 pub mod array_2d;
+#[cfg(feature = "const_generics")]
+pub mod inline_grid;
+#[cfg(feature = "alloc")]
 pub mod vec_2d;
 
 pub mod prelude {
+    pub use crate::col_major::{ColMajorSlice2D, ColMajorSlice2DMut};
+    pub use crate::map::MapView;
+    pub use crate::padded::{BorderMode, Padded};
     pub use crate::slice::{Slice2D, Slice2DMut};
+    pub use crate::stack::{HStack, HStackMut, VStack, VStackMut};
+    pub use crate::strided::{StridedSlice, StridedSliceMut};
+    pub use crate::wrapping::{Wrapping, WrappingMut};
+
+    #[cfg(feature = "const_generics")]
+    pub use crate::array_2d::Array2D;
+    #[cfg(feature = "const_generics")]
+    pub use crate::inline_grid::InlineGrid;
+    #[cfg(feature = "alloc")]
+    pub use crate::vec_2d::Vec2D;
 
+    pub use crate::diag::{Slice2DDiag, Slice2DDiagMut};
     pub use crate::fill::Slice2DFill;
     pub use crate::index::{GetElemRef, GetElemRefMut};
     pub use crate::iter::{Slice2DIter, Slice2DIterMut};
     pub use crate::slice::{Shape2D, Shape2DExt};
     pub use crate::split::{Split, SplitMut};
     pub use crate::swap::Slice2DSwap;
+    pub use crate::windows::Slice2DWindows;
 }
 
 pub use crate::prelude::*;
@@ -0,0 +1,141 @@
+use crate::{
+    index::{GetElemRef, IRange},
+    iter::{Cols, Row, Rows, Slice2DIter},
+    slice::{Shape2D, Slice2D, Slice2DRawRef},
+    strided,
+};
+use core::iter::Map;
+
+// Read-only view whose elements are computed from the source by `f` each
+// time they are read.
+pub struct MapView<'a, T, F> {
+    src: Slice2D<'a, T>,
+    f: F,
+}
+
+pub trait MapIndex<'b, T, F> {
+    type Output;
+    fn get(self, view: &'b MapView<'_, T, F>) -> Option<Self::Output>;
+}
+
+impl<'a, T, U, F> MapView<'a, T, F>
+where
+    F: Fn(&T) -> U,
+{
+    pub fn new(src: Slice2D<'a, T>, f: F) -> MapView<'a, T, F> {
+        MapView { src, f }
+    }
+    #[inline]
+    pub fn get_shape(&self) -> (usize, usize) {
+        (self.src.get_row(), self.src.get_col())
+    }
+    #[inline]
+    pub fn get_row(&self) -> usize {
+        self.src.get_row()
+    }
+    #[inline]
+    pub fn get_col(&self) -> usize {
+        self.src.get_col()
+    }
+    #[inline]
+    pub fn get<'b, I>(&'b self, index: I) -> Option<I::Output>
+    where
+        I: MapIndex<'b, T, F>,
+    {
+        index.get(self)
+    }
+    pub fn row_iter(&self) -> MapRows<'_, T, F> {
+        MapRows {
+            rows: self.src.row_iter(),
+            f: &self.f,
+        }
+    }
+    pub fn col_iter(&self) -> MapCols<'_, T, F> {
+        MapCols {
+            cols: self.src.col_iter(),
+            f: &self.f,
+        }
+    }
+}
+
+impl<'a, T, F: Clone> Clone for MapView<'a, T, F> {
+    fn clone(&self) -> Self {
+        MapView {
+            src: unsafe { Slice2D::from_slice_2d_raw(*self.src.get_slice_2d_raw()) },
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<'b, T, U, F> MapIndex<'b, T, F> for (usize, usize)
+where
+    F: Fn(&T) -> U,
+{
+    type Output = U;
+
+    #[inline]
+    fn get(self, view: &'b MapView<'_, T, F>) -> Option<Self::Output> {
+        view.src.get(self).map(&view.f)
+    }
+}
+
+impl<'b, T, U, F, B1, B2> MapIndex<'b, T, F> for (B1, B2)
+where
+    T: 'b,
+    F: Fn(&T) -> U + 'b,
+    B1: IRange,
+    B2: IRange,
+{
+    type Output = MapView<'b, T, &'b F>;
+
+    fn get(self, view: &'b MapView<'_, T, F>) -> Option<Self::Output> {
+        let src = view.src.get(self)?;
+        Some(MapView { src, f: &view.f })
+    }
+}
+
+pub struct MapRows<'b, T, F> {
+    rows: Rows<'b, T>,
+    f: &'b F,
+}
+
+impl<'b, T, U, F> Iterator for MapRows<'b, T, F>
+where
+    F: Fn(&T) -> U,
+{
+    type Item = Map<Row<'b, T>, &'b F>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = self.f;
+        self.rows.next().map(|row| row.map(f))
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+impl<'b, T, U, F> ExactSizeIterator for MapRows<'b, T, F> where F: Fn(&T) -> U {}
+
+pub struct MapCols<'b, T, F> {
+    cols: Cols<'b, T>,
+    f: &'b F,
+}
+
+impl<'b, T, U, F> Iterator for MapCols<'b, T, F>
+where
+    F: Fn(&T) -> U,
+{
+    type Item = Map<strided::Iter<'b, T>, &'b F>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = self.f;
+        self.cols.next().map(|col| col.into_iter().map(f))
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cols.size_hint()
+    }
+}
+impl<'b, T, U, F> ExactSizeIterator for MapCols<'b, T, F> where F: Fn(&T) -> U {}
@@ -0,0 +1,291 @@
+use crate::{
+    index::IRange,
+    slice::{Shape2D, Slice2D, Slice2DRaw, Slice2DRawRef},
+    utils::calc_2d_index,
+    windows::PaddedWindows,
+};
+use core::{iter::FusedIterator, marker::PhantomData, ops::Index};
+
+// what reads outside of the source return, with the source `abcd`:
+//   Constant(v)  vv|abcd|vv
+//   Clamp        aa|abcd|dd
+//   Reflect      ba|abcd|dc
+//   Reflect101   cb|abcd|cb
+//   Wrap         cd|abcd|ab
+#[derive(Hash, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BorderMode<T> {
+    Constant(T),
+    Clamp,
+    Reflect,
+    Reflect101,
+    Wrap,
+}
+
+// Read-only view of a source surrounded by a virtual border.
+// `origin` is the position of the top left corner of the view in the
+// source, so it is negative on the sides that are padded, and `step` the
+// distance in the source between two neighbouring cells of the view.
+pub struct Padded<'a, T> {
+    src: Slice2DRaw<T>,
+    origin: (isize, isize),
+    step: (usize, usize),
+    shape: (usize, usize),
+    mode: BorderMode<T>,
+    _marker: PhantomData<&'a T>,
+}
+
+pub trait PaddedIndex<'a, T> {
+    type Ref;
+    fn get(self, view: &'a Padded<'_, T>) -> Option<Self::Ref>;
+}
+
+// source position of `i` on an axis of `len` cells, `None` if it falls on
+// a constant border
+#[inline]
+fn calc_border_index<T>(mode: &BorderMode<T>, i: isize, len: usize) -> Option<usize> {
+    if 0 <= i && (i as usize) < len {
+        return Some(i as usize);
+    }
+    if len == 0 {
+        return None;
+    }
+    let n = len as isize;
+    match mode {
+        BorderMode::Constant(_) => None,
+        BorderMode::Clamp => Some(i.clamp(0, n - 1) as usize),
+        BorderMode::Reflect => {
+            let m = i.rem_euclid(2 * n);
+            Some(if m < n { m } else { 2 * n - 1 - m } as usize)
+        }
+        BorderMode::Reflect101 if len == 1 => Some(0),
+        BorderMode::Reflect101 => {
+            let m = i.rem_euclid(2 * n - 2);
+            Some(if m < n { m } else { 2 * n - 2 - m } as usize)
+        }
+        BorderMode::Wrap => Some(i.rem_euclid(n) as usize),
+    }
+}
+
+impl<'a, T> Padded<'a, T> {
+    pub fn new(
+        src: Slice2D<'a, T>,
+        top: usize,
+        bottom: usize,
+        left: usize,
+        right: usize,
+        mode: BorderMode<T>,
+    ) -> Padded<'a, T> {
+        let row = src
+            .get_row()
+            .checked_add(top)
+            .and_then(|n| n.checked_add(bottom))
+            .filter(|&n| n <= isize::MAX as usize);
+        let col = src
+            .get_col()
+            .checked_add(left)
+            .and_then(|n| n.checked_add(right))
+            .filter(|&n| n <= isize::MAX as usize);
+        let shape = row.zip(col).expect("padding is too large.");
+        assert!(
+            matches!(mode, BorderMode::Constant(_))
+                || shape.0 == 0
+                || shape.1 == 0
+                || (src.get_row() != 0 && src.get_col() != 0),
+            "nothing to pad from, the slice is empty."
+        );
+        Padded {
+            src: *src.get_slice_2d_raw(),
+            origin: (-(top as isize), -(left as isize)),
+            step: (1, 1),
+            shape,
+            mode,
+            _marker: PhantomData,
+        }
+    }
+    #[inline]
+    pub fn get_shape(&self) -> (usize, usize) {
+        self.shape
+    }
+    #[inline]
+    pub fn get_row(&self) -> usize {
+        self.shape.0
+    }
+    #[inline]
+    pub fn get_col(&self) -> usize {
+        self.shape.1
+    }
+    #[inline]
+    pub fn mode(&self) -> &BorderMode<T> {
+        &self.mode
+    }
+    #[inline]
+    pub fn get<'b, I>(&'b self, index: I) -> Option<I::Ref>
+    where
+        I: PaddedIndex<'b, T>,
+    {
+        index.get(self)
+    }
+    // overlapping `shape` sub-views, in row-major order of their corners
+    pub fn windows(&self, shape: (usize, usize)) -> PaddedWindows<'_, 'a, T>
+    where
+        T: Clone,
+    {
+        PaddedWindows::new(self, shape)
+    }
+    pub fn row_iter(&self) -> PaddedRows<'_, 'a, T> {
+        PaddedRows {
+            view: self,
+            r: 0,
+            end: self.shape.0,
+        }
+    }
+
+    // element at `(r, c)` of the source, or the border value
+    #[inline]
+    fn get_src(&self, r: Option<usize>, c: Option<usize>) -> Option<&T> {
+        match (r, c) {
+            (Some(r), Some(c)) => unsafe {
+                Some(&*self.src.slice.offset(calc_2d_index(r, c, &self.src)))
+            },
+            _ => match &self.mode {
+                BorderMode::Constant(v) => Some(v),
+                _ => None,
+            },
+        }
+    }
+    #[inline]
+    fn src_row(&self, r: usize) -> Option<usize> {
+        calc_border_index(
+            &self.mode,
+            self.origin.0 + (r * self.step.0) as isize,
+            self.src.get_row(),
+        )
+    }
+    #[inline]
+    fn src_col(&self, c: usize) -> Option<usize> {
+        calc_border_index(
+            &self.mode,
+            self.origin.1 + (c * self.step.1) as isize,
+            self.src.get_col(),
+        )
+    }
+}
+
+impl<'a, T: Clone> Clone for Padded<'a, T> {
+    fn clone(&self) -> Self {
+        Padded {
+            src: self.src,
+            origin: self.origin,
+            step: self.step,
+            shape: self.shape,
+            mode: self.mode.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'b, T: 'b> PaddedIndex<'b, T> for (usize, usize) {
+    type Ref = &'b T;
+
+    #[inline]
+    fn get(self, view: &'b Padded<'_, T>) -> Option<Self::Ref> {
+        if self.0 < view.shape.0 && self.1 < view.shape.1 {
+            view.get_src(view.src_row(self.0), view.src_col(self.1))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'b, T, B1, B2> PaddedIndex<'b, T> for (B1, B2)
+where
+    T: Clone + 'b,
+    B1: IRange,
+    B2: IRange,
+{
+    type Ref = Padded<'b, T>;
+
+    fn get(self, view: &'b Padded<'_, T>) -> Option<Self::Ref> {
+        let (rs, rn, rstep) = self.0.calc_range(view.shape.0)?;
+        let (cs, cn, cstep) = self.1.calc_range(view.shape.1)?;
+        Some(Padded {
+            src: view.src,
+            origin: (
+                view.origin.0 + (rs * view.step.0) as isize,
+                view.origin.1 + (cs * view.step.1) as isize,
+            ),
+            step: (view.step.0 * rstep, view.step.1 * cstep),
+            shape: (rn, cn),
+            mode: view.mode.clone(),
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for Padded<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: (usize, usize)) -> &T {
+        self.get(index).expect("out of range")
+    }
+}
+
+pub struct PaddedRows<'b, 'a, T> {
+    view: &'b Padded<'a, T>,
+    r: usize,
+    end: usize,
+}
+
+impl<'b, 'a, T> Iterator for PaddedRows<'b, 'a, T> {
+    type Item = PaddedRow<'b, 'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.r < self.end {
+            let r = self.view.src_row(self.r);
+            self.r += 1;
+            Some(PaddedRow {
+                view: self.view,
+                r,
+                c: 0,
+                end: self.view.shape.1,
+            })
+        } else {
+            None
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.end - self.r;
+        (n, Some(n))
+    }
+}
+impl<'b, 'a, T> ExactSizeIterator for PaddedRows<'b, 'a, T> {}
+impl<'b, 'a, T> FusedIterator for PaddedRows<'b, 'a, T> {}
+
+// one row of a padded view, the source row is resolved once
+pub struct PaddedRow<'b, 'a, T> {
+    view: &'b Padded<'a, T>,
+    r: Option<usize>,
+    c: usize,
+    end: usize,
+}
+
+impl<'b, 'a, T> Iterator for PaddedRow<'b, 'a, T> {
+    type Item = &'b T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.c < self.end {
+            let c = self.view.src_col(self.c);
+            self.c += 1;
+            self.view.get_src(self.r, c)
+        } else {
+            None
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.end - self.c;
+        (n, Some(n))
+    }
+}
+impl<'b, 'a, T> ExactSizeIterator for PaddedRow<'b, 'a, T> {}
+impl<'b, 'a, T> FusedIterator for PaddedRow<'b, 'a, T> {}
@@ -1,7 +1,16 @@
+use crate::{
+    map::MapView,
+    padded::{BorderMode, Padded},
+    utils::calc_strided_len,
+    wrapping::{Wrapping, WrappingMut},
+};
 use core::{convert::From, marker::PhantomData, ptr::null};
 
 pub trait Shape2D {
-    fn get_base_col(&self) -> usize;
+    // distance between `(r, c)` and `(r + 1, c)` in the underlying slice
+    fn get_row_stride(&self) -> isize;
+    // distance between `(r, c)` and `(r, c + 1)` in the underlying slice
+    fn get_col_stride(&self) -> isize;
     fn get_row(&self) -> usize;
     fn get_col(&self) -> usize;
 }
@@ -27,37 +36,123 @@ pub trait SlicePtr<T> {
 }
 
 pub trait SlicePtrMut<T> {
-    fn get_slice_ptr_mut(&self) -> *mut T;
+    fn get_slice_ptr_mut(&mut self) -> *mut T;
 }
 
-#[derive(Hash, Debug, Clone)]
+#[derive(Hash, Debug)]
 pub struct Slice2DRaw<T> {
-    slice: *const T,
+    pub(crate) slice: *const T,
 
-    base_col: usize,
+    row_stride: isize,
+    col_stride: isize,
     row: usize,
     col: usize,
 }
 
+// not derived, that would require `T: Clone`
+impl<T> Clone for Slice2DRaw<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Slice2DRaw<T> {}
+
 impl<T> Slice2DRaw<T> {
-    unsafe fn from_raw_parts(
+    pub(crate) unsafe fn from_raw_parts(
         slice: *const T,
-        base_col: usize,
+        row_stride: isize,
+        col_stride: isize,
         row: usize,
         col: usize,
     ) -> Slice2DRaw<T> {
         Slice2DRaw {
             slice,
-            base_col,
+            row_stride,
+            col_stride,
             row,
             col,
         }
     }
+    // the layout of `slice_2d`
+    pub(crate) fn from_shape<S>(slice_2d: &S) -> Slice2DRaw<T>
+    where
+        S: Shape2D + SlicePtr<T>,
+    {
+        Slice2DRaw {
+            slice: slice_2d.get_slice_ptr(),
+            row_stride: slice_2d.get_row_stride(),
+            col_stride: slice_2d.get_col_stride(),
+            row: slice_2d.get_row(),
+            col: slice_2d.get_col(),
+        }
+    }
+    // the layout of `slice_2d`, pointing at it for writes
+    pub(crate) fn from_shape_mut<S>(slice_2d: &mut S) -> Slice2DRaw<T>
+    where
+        S: Shape2D + SlicePtrMut<T>,
+    {
+        Slice2DRaw {
+            row_stride: slice_2d.get_row_stride(),
+            col_stride: slice_2d.get_col_stride(),
+            row: slice_2d.get_row(),
+            col: slice_2d.get_col(),
+            slice: slice_2d.get_slice_ptr_mut(),
+        }
+    }
+    // rows and columns swap places, the data is left untouched
+    fn transposed(&self) -> Slice2DRaw<T> {
+        Slice2DRaw {
+            slice: self.slice,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            row: self.col,
+            col: self.row,
+        }
+    }
+    // start from the last row and walk the rows backwards
+    fn flipped_rows(&self) -> Slice2DRaw<T> {
+        Slice2DRaw {
+            slice: self
+                .slice
+                .wrapping_offset(self.row.saturating_sub(1) as isize * self.row_stride),
+            row_stride: -self.row_stride,
+            col_stride: self.col_stride,
+            row: self.row,
+            col: self.col,
+        }
+    }
+    // every `row_step`-th row and `col_step`-th column, from the first one
+    fn stepped(&self, row_step: usize, col_step: usize) -> Slice2DRaw<T> {
+        assert!(row_step != 0 && col_step != 0, "step is zero.");
+        // a step past the end selects only the first one, clamping it keeps
+        // the stride from overflowing
+        let row_step = row_step.min(self.row.max(1));
+        let col_step = col_step.min(self.col.max(1));
+        Slice2DRaw {
+            slice: self.slice,
+            row_stride: self.row_stride * row_step as isize,
+            col_stride: self.col_stride * col_step as isize,
+            row: self.row.div_ceil(row_step),
+            col: self.col.div_ceil(col_step),
+        }
+    }
+    fn flipped_cols(&self) -> Slice2DRaw<T> {
+        Slice2DRaw {
+            slice: self
+                .slice
+                .wrapping_offset(self.col.saturating_sub(1) as isize * self.col_stride),
+            row_stride: self.row_stride,
+            col_stride: -self.col_stride,
+            row: self.row,
+            col: self.col,
+        }
+    }
 }
 
 impl<T> Default for Slice2DRaw<T> {
     fn default() -> Self {
-        unsafe { Self::from_raw_parts(null(), 0, 0, 0) }
+        unsafe { Self::from_raw_parts(null(), 0, 0, 0, 0) }
     }
 }
 
@@ -87,8 +182,13 @@ where
     S: Slice2DRawRef<DataT = T>,
 {
     #[inline(always)]
-    fn get_base_col(&self) -> usize {
-        self.get_slice_2d_raw().base_col
+    fn get_row_stride(&self) -> isize {
+        self.get_slice_2d_raw().row_stride
+    }
+
+    #[inline(always)]
+    fn get_col_stride(&self) -> isize {
+        self.get_slice_2d_raw().col_stride
     }
 
     #[inline(always)]
@@ -133,20 +233,97 @@ impl<'a, T> Slice2D<'a, T> {
         );
         unsafe { Slice2D::from_raw_parts(slice.as_ptr(), col, row, col) }
     }
+    pub fn from_slice_with_stride(
+        slice: &[T],
+        offset: usize,
+        row: usize,
+        col: usize,
+        row_stride: usize,
+    ) -> Slice2D<'_, T> {
+        assert!(row <= 1 || row_stride >= col, "rows overlap each other.");
+        assert!(
+            calc_strided_len(offset, row, col, row_stride).is_some_and(|len| len <= slice.len()),
+            "slice does not contain enough space."
+        );
+        unsafe { Slice2D::from_raw_parts(slice.as_ptr().add(offset), row_stride, row, col) }
+    }
+    /// # Safety
+    /// `slice` must point to `row` rows of `col` elements, the rows `base_col`
+    /// elements apart, valid for reads for `'b` and not mutated during it.
     pub unsafe fn from_raw_parts<'b>(
         slice: *const T,
         base_col: usize,
         row: usize,
         col: usize,
     ) -> Slice2D<'b, T> {
-        Slice2D::from_slice_2d_raw(Slice2DRaw::from_raw_parts(slice, base_col, row, col))
+        Slice2D::from_strided_raw_parts(slice, base_col as isize, 1, row, col)
     }
+    /// # Safety
+    /// every `slice + r * row_stride + c * col_stride` with `r < row` and
+    /// `c < col` must be valid for reads for `'b` and not mutated during it.
+    pub unsafe fn from_strided_raw_parts<'b>(
+        slice: *const T,
+        row_stride: isize,
+        col_stride: isize,
+        row: usize,
+        col: usize,
+    ) -> Slice2D<'b, T> {
+        Slice2D::from_slice_2d_raw(Slice2DRaw::from_raw_parts(
+            slice, row_stride, col_stride, row, col,
+        ))
+    }
+    /// # Safety
+    /// the parts of `raw` must satisfy the contract of `from_strided_raw_parts`.
     pub unsafe fn from_slice_2d_raw<'b>(raw: Slice2DRaw<T>) -> Slice2D<'b, T> {
         Slice2D {
             raw,
             _marker: PhantomData,
         }
     }
+
+    #[inline]
+    pub fn transposed(&self) -> Slice2D<'a, T> {
+        unsafe { Slice2D::from_slice_2d_raw(self.raw.transposed()) }
+    }
+    #[inline]
+    pub fn flip_rows(&self) -> Slice2D<'a, T> {
+        unsafe { Slice2D::from_slice_2d_raw(self.raw.flipped_rows()) }
+    }
+    #[inline]
+    pub fn flip_cols(&self) -> Slice2D<'a, T> {
+        unsafe { Slice2D::from_slice_2d_raw(self.raw.flipped_cols()) }
+    }
+    #[inline]
+    pub fn rotated_180(&self) -> Slice2D<'a, T> {
+        unsafe { Slice2D::from_slice_2d_raw(self.raw.flipped_rows().flipped_cols()) }
+    }
+    #[inline]
+    pub fn step_by(&self, row_step: usize, col_step: usize) -> Slice2D<'a, T> {
+        unsafe { Slice2D::from_slice_2d_raw(self.raw.stepped(row_step, col_step)) }
+    }
+    #[inline]
+    pub fn padded(
+        &self,
+        top: usize,
+        bottom: usize,
+        left: usize,
+        right: usize,
+        mode: BorderMode<T>,
+    ) -> Padded<'a, T> {
+        let src = unsafe { Slice2D::from_slice_2d_raw(self.raw) };
+        Padded::new(src, top, bottom, left, right, mode)
+    }
+    #[inline]
+    pub fn map_view<U, F>(&self, f: F) -> MapView<'a, T, F>
+    where
+        F: Fn(&T) -> U,
+    {
+        MapView::new(unsafe { Slice2D::from_slice_2d_raw(self.raw) }, f)
+    }
+    #[inline]
+    pub fn wrapping(&self) -> Wrapping<'a, T> {
+        Wrapping::new(unsafe { Slice2D::from_slice_2d_raw(self.raw) })
+    }
 }
 impl<'a, T> Slice2DRawRef for Slice2D<'a, T> {
     type DataT = T;
@@ -184,20 +361,80 @@ impl<'a, T> Slice2DMut<'a, T> {
         );
         unsafe { Slice2DMut::from_raw_parts(slice.as_mut_ptr(), col, row, col) }
     }
+    pub fn from_slice_with_stride(
+        slice: &mut [T],
+        offset: usize,
+        row: usize,
+        col: usize,
+        row_stride: usize,
+    ) -> Slice2DMut<'_, T> {
+        assert!(row <= 1 || row_stride >= col, "rows overlap each other.");
+        assert!(
+            calc_strided_len(offset, row, col, row_stride).is_some_and(|len| len <= slice.len()),
+            "slice does not contain enough space."
+        );
+        unsafe { Slice2DMut::from_raw_parts(slice.as_mut_ptr().add(offset), row_stride, row, col) }
+    }
+    /// # Safety
+    /// `slice` must point to `row` rows of `col` elements, the rows `base_col`
+    /// elements apart, with `base_col >= col` if `row > 1`, valid for reads
+    /// and writes for `'b` and not accessed through any other pointer during it.
     pub unsafe fn from_raw_parts<'b>(
         slice: *mut T,
         base_col: usize,
         row: usize,
         col: usize,
     ) -> Slice2DMut<'b, T> {
-        Slice2DMut::from_slice_2d_raw(Slice2DRaw::from_raw_parts(slice, base_col, row, col))
+        Slice2DMut::from_strided_raw_parts(slice, base_col as isize, 1, row, col)
+    }
+    /// # Safety
+    /// every `slice + r * row_stride + c * col_stride` with `r < row` and
+    /// `c < col` must be valid for reads and writes for `'b`, not accessed
+    /// through any other pointer during it, and distinct for distinct `(r, c)`.
+    pub unsafe fn from_strided_raw_parts<'b>(
+        slice: *mut T,
+        row_stride: isize,
+        col_stride: isize,
+        row: usize,
+        col: usize,
+    ) -> Slice2DMut<'b, T> {
+        Slice2DMut::from_slice_2d_raw(Slice2DRaw::from_raw_parts(
+            slice, row_stride, col_stride, row, col,
+        ))
     }
+    /// # Safety
+    /// the parts of `raw` must satisfy the contract of `from_strided_raw_parts`.
     pub unsafe fn from_slice_2d_raw<'b>(raw: Slice2DRaw<T>) -> Slice2DMut<'b, T> {
         Slice2DMut {
             raw,
             _marker: PhantomData,
         }
     }
+
+    #[inline]
+    pub fn transposed(self) -> Slice2DMut<'a, T> {
+        unsafe { Slice2DMut::from_slice_2d_raw(self.raw.transposed()) }
+    }
+    #[inline]
+    pub fn flip_rows(self) -> Slice2DMut<'a, T> {
+        unsafe { Slice2DMut::from_slice_2d_raw(self.raw.flipped_rows()) }
+    }
+    #[inline]
+    pub fn flip_cols(self) -> Slice2DMut<'a, T> {
+        unsafe { Slice2DMut::from_slice_2d_raw(self.raw.flipped_cols()) }
+    }
+    #[inline]
+    pub fn rotated_180(self) -> Slice2DMut<'a, T> {
+        unsafe { Slice2DMut::from_slice_2d_raw(self.raw.flipped_rows().flipped_cols()) }
+    }
+    #[inline]
+    pub fn step_by(self, row_step: usize, col_step: usize) -> Slice2DMut<'a, T> {
+        unsafe { Slice2DMut::from_slice_2d_raw(self.raw.stepped(row_step, col_step)) }
+    }
+    #[inline]
+    pub fn wrapping(self) -> WrappingMut<'a, T> {
+        WrappingMut::new(self)
+    }
 }
 impl<'a, T> SlicePtr<T> for Slice2DMut<'a, T> {
     fn get_slice_ptr(&self) -> *const T {
@@ -205,7 +442,7 @@ impl<'a, T> SlicePtr<T> for Slice2DMut<'a, T> {
     }
 }
 impl<'a, T> SlicePtrMut<T> for Slice2DMut<'a, T> {
-    fn get_slice_ptr_mut(&self) -> *mut T {
+    fn get_slice_ptr_mut(&mut self) -> *mut T {
         self.raw.slice as *mut T
     }
 }
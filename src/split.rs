@@ -1,6 +1,6 @@
 use crate::{
     index::GetElemRef,
-    slice::{Shape2D, Slice2D, Slice2DMut, SlicePtr, SlicePtrMut},
+    slice::{Shape2D, Slice2D, Slice2DMut, Slice2DRaw, SlicePtr, SlicePtrMut},
     utils::calc_2d_index,
 };
 
@@ -56,25 +56,36 @@ pub trait SplitMut<'a, T> {
     fn split_at_mut(&'a mut self, idx: (usize, usize)) -> Option<[[Slice2DMut<'a, T>; 2]; 2]>;
 }
 
+// the layout of `g` and one pointer for all the parts, a second mutable
+// borrow would invalidate the first pointer
+#[inline(always)]
+fn raw_parts_mut<T, G: Shape2D + SlicePtrMut<T>>(g: &mut G) -> (Slice2DRaw<T>, *mut T) {
+    let raw = Slice2DRaw::from_shape_mut(g);
+    (raw, raw.slice as *mut T)
+}
+
 impl<'a, T, G> SplitMut<'a, T> for G
 where
     G: Shape2D + SlicePtrMut<T>,
 {
     fn split_at_vertically_mut(&'a mut self, j: usize) -> Option<[Slice2DMut<'a, T>; 2]> {
         if j < self.get_col() {
+            let (raw, ptr) = raw_parts_mut(self);
             unsafe {
                 Some([
-                    Slice2DMut::from_raw_parts(
-                        self.get_slice_ptr_mut(),
-                        self.get_base_col(),
-                        self.get_row(),
+                    Slice2DMut::from_strided_raw_parts(
+                        ptr,
+                        raw.get_row_stride(),
+                        raw.get_col_stride(),
+                        raw.get_row(),
                         j,
                     ),
-                    Slice2DMut::from_raw_parts(
-                        self.get_slice_ptr_mut().add(calc_2d_index(0, j, self)),
-                        self.get_base_col(),
-                        self.get_row(),
-                        self.get_col() - j,
+                    Slice2DMut::from_strided_raw_parts(
+                        ptr.offset(calc_2d_index(0, j, &raw)),
+                        raw.get_row_stride(),
+                        raw.get_col_stride(),
+                        raw.get_row(),
+                        raw.get_col() - j,
                     ),
                 ])
             }
@@ -84,19 +95,22 @@ where
     }
     fn split_at_horizontally_mut(&'a mut self, i: usize) -> Option<[Slice2DMut<'a, T>; 2]> {
         if i < self.get_row() {
+            let (raw, ptr) = raw_parts_mut(self);
             unsafe {
                 Some([
-                    Slice2DMut::from_raw_parts(
-                        self.get_slice_ptr_mut(),
-                        self.get_base_col(),
+                    Slice2DMut::from_strided_raw_parts(
+                        ptr,
+                        raw.get_row_stride(),
+                        raw.get_col_stride(),
                         i,
-                        self.get_col(),
+                        raw.get_col(),
                     ),
-                    Slice2DMut::from_raw_parts(
-                        self.get_slice_ptr_mut().add(calc_2d_index(i, 0, self)),
-                        self.get_base_col(),
-                        self.get_row() - i,
-                        self.get_col(),
+                    Slice2DMut::from_strided_raw_parts(
+                        ptr.offset(calc_2d_index(i, 0, &raw)),
+                        raw.get_row_stride(),
+                        raw.get_col_stride(),
+                        raw.get_row() - i,
+                        raw.get_col(),
                     ),
                 ])
             }
@@ -107,34 +121,39 @@ where
     fn split_at_mut(&'a mut self, idx: (usize, usize)) -> Option<[[Slice2DMut<'a, T>; 2]; 2]> {
         let (i, j) = idx;
         if i <= self.get_row() && j <= self.get_col() {
+            let (raw, ptr) = raw_parts_mut(self);
             unsafe {
                 Some([
                     [
-                        Slice2DMut::from_raw_parts(
-                            self.get_slice_ptr_mut(),
-                            self.get_base_col(),
+                        Slice2DMut::from_strided_raw_parts(
+                            ptr,
+                            raw.get_row_stride(),
+                            raw.get_col_stride(),
                             i,
                             j,
                         ),
-                        Slice2DMut::from_raw_parts(
-                            self.get_slice_ptr_mut().add(calc_2d_index(i, 0, self)),
-                            self.get_base_col(),
+                        Slice2DMut::from_strided_raw_parts(
+                            ptr.offset(calc_2d_index(0, j, &raw)),
+                            raw.get_row_stride(),
+                            raw.get_col_stride(),
                             i,
-                            self.get_col() - j,
+                            raw.get_col() - j,
                         ),
                     ],
                     [
-                        Slice2DMut::from_raw_parts(
-                            self.get_slice_ptr_mut().add(calc_2d_index(0, j, self)),
-                            self.get_base_col(),
-                            self.get_row() - i,
+                        Slice2DMut::from_strided_raw_parts(
+                            ptr.offset(calc_2d_index(i, 0, &raw)),
+                            raw.get_row_stride(),
+                            raw.get_col_stride(),
+                            raw.get_row() - i,
                             j,
                         ),
-                        Slice2DMut::from_raw_parts(
-                            self.get_slice_ptr_mut().add(calc_2d_index(i, j, self)),
-                            self.get_base_col(),
-                            self.get_row() - i,
-                            self.get_col() - j,
+                        Slice2DMut::from_strided_raw_parts(
+                            ptr.offset(calc_2d_index(i, j, &raw)),
+                            raw.get_row_stride(),
+                            raw.get_col_stride(),
+                            raw.get_row() - i,
+                            raw.get_col() - j,
                         ),
                     ],
                 ])
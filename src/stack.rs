@@ -0,0 +1,584 @@
+use crate::{
+    index::{GetElemRef, GetElemRefMut},
+    iter::{Row, RowMut},
+    slice::{Shape2D, Slice2D, Slice2DMut, SlicePtr, SlicePtrMut},
+};
+use core::{
+    fmt,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+// Concatenations of views, side by side (`HStack`) or on top of each
+// other (`VStack`). The parts are borrowed as they are, nothing is copied.
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ShapeMismatchError {
+    // part `index` does not line up with the ones before it
+    Mismatch {
+        index: usize,
+        expected: usize,
+        found: usize,
+    },
+    // the stacked axis overflows `usize` at part `index`
+    Overflow {
+        index: usize,
+    },
+}
+
+impl fmt::Display for ShapeMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeMismatchError::Mismatch {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "part {} has {} cells along the shared axis, expected {}",
+                index, found, expected
+            ),
+            ShapeMismatchError::Overflow { index } => {
+                write!(f, "part {} overflows the stacked axis", index)
+            }
+        }
+    }
+}
+
+// length of the shared axis and sum of the stacked one
+fn calc_stack_shape<S, F, G>(
+    parts: &[S],
+    shared: F,
+    stacked: G,
+) -> Result<(usize, usize), ShapeMismatchError>
+where
+    F: Fn(&S) -> usize,
+    G: Fn(&S) -> usize,
+{
+    let expected = parts.first().map_or(0, &shared);
+    let mut sum: usize = 0;
+    for (index, part) in parts.iter().enumerate() {
+        let found = shared(part);
+        if found != expected {
+            return Err(ShapeMismatchError::Mismatch {
+                index,
+                expected,
+                found,
+            });
+        }
+        sum = sum
+            .checked_add(stacked(part))
+            .ok_or(ShapeMismatchError::Overflow { index })?;
+    }
+    Ok((expected, sum))
+}
+
+// part containing `i` along the stacked axis, and `i` inside of it
+#[inline]
+fn find_part<S, G: Fn(&S) -> usize>(
+    parts: &[S],
+    mut i: usize,
+    stacked: G,
+) -> Option<(usize, usize)> {
+    for (p, part) in parts.iter().enumerate() {
+        let n = stacked(part);
+        if i < n {
+            return Some((p, i));
+        }
+        i -= n;
+    }
+    None
+}
+
+pub struct HStack<'b, 'a, T> {
+    parts: &'b [Slice2D<'a, T>],
+    row: usize,
+    col: usize,
+}
+
+pub struct VStack<'b, 'a, T> {
+    parts: &'b [Slice2D<'a, T>],
+    row: usize,
+    col: usize,
+}
+
+pub struct HStackMut<'b, 'a, T> {
+    parts: &'b mut [Slice2DMut<'a, T>],
+    row: usize,
+    col: usize,
+}
+
+pub struct VStackMut<'b, 'a, T> {
+    parts: &'b mut [Slice2DMut<'a, T>],
+    row: usize,
+    col: usize,
+}
+
+pub fn hstack<'b, 'a, T>(
+    parts: &'b [Slice2D<'a, T>],
+) -> Result<HStack<'b, 'a, T>, ShapeMismatchError> {
+    let (row, col) = calc_stack_shape(parts, |s| s.get_row(), |s| s.get_col())?;
+    Ok(HStack { parts, row, col })
+}
+
+pub fn vstack<'b, 'a, T>(
+    parts: &'b [Slice2D<'a, T>],
+) -> Result<VStack<'b, 'a, T>, ShapeMismatchError> {
+    let (col, row) = calc_stack_shape(parts, |s| s.get_col(), |s| s.get_row())?;
+    Ok(VStack { parts, row, col })
+}
+
+pub fn hstack_mut<'b, 'a, T>(
+    parts: &'b mut [Slice2DMut<'a, T>],
+) -> Result<HStackMut<'b, 'a, T>, ShapeMismatchError> {
+    let (row, col) = calc_stack_shape(parts, |s| s.get_row(), |s| s.get_col())?;
+    Ok(HStackMut { parts, row, col })
+}
+
+pub fn vstack_mut<'b, 'a, T>(
+    parts: &'b mut [Slice2DMut<'a, T>],
+) -> Result<VStackMut<'b, 'a, T>, ShapeMismatchError> {
+    let (col, row) = calc_stack_shape(parts, |s| s.get_col(), |s| s.get_row())?;
+    Ok(VStackMut { parts, row, col })
+}
+
+// methods shared by every stack, `$locate` maps `(r, c)` to a part and the
+// position inside of it
+macro_rules! impl_stack_common {
+    ($t:ident, $locate:ident) => {
+        impl<'b, 'a, T> $t<'b, 'a, T> {
+            #[inline]
+            pub fn get_shape(&self) -> (usize, usize) {
+                (self.row, self.col)
+            }
+            #[inline]
+            pub fn get_row(&self) -> usize {
+                self.row
+            }
+            #[inline]
+            pub fn get_col(&self) -> usize {
+                self.col
+            }
+            #[inline]
+            pub fn get(&self, (r, c): (usize, usize)) -> Option<&T> {
+                if r < self.row && c < self.col {
+                    let (p, r, c) = $locate(&self.parts[..], r, c)?;
+                    self.parts[p].get((r, c))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<'b, 'a, T> Index<(usize, usize)> for $t<'b, 'a, T> {
+            type Output = T;
+
+            #[inline]
+            fn index(&self, index: (usize, usize)) -> &T {
+                self.get(index).expect("out of range")
+            }
+        }
+    };
+}
+
+macro_rules! impl_stack_mut {
+    ($t:ident, $locate:ident) => {
+        impl<'b, 'a, T> $t<'b, 'a, T> {
+            #[inline]
+            pub fn get_mut(&mut self, (r, c): (usize, usize)) -> Option<&mut T> {
+                if r < self.row && c < self.col {
+                    let (p, r, c) = $locate(&self.parts[..], r, c)?;
+                    self.parts[p].get_mut((r, c))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<'b, 'a, T> IndexMut<(usize, usize)> for $t<'b, 'a, T> {
+            #[inline]
+            fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+                self.get_mut(index).expect("out of range")
+            }
+        }
+    };
+}
+
+#[inline]
+fn locate_h<S: Shape2D>(parts: &[S], r: usize, c: usize) -> Option<(usize, usize, usize)> {
+    let (p, c) = find_part(parts, c, |s| s.get_col())?;
+    Some((p, r, c))
+}
+
+#[inline]
+fn locate_v<S: Shape2D>(parts: &[S], r: usize, c: usize) -> Option<(usize, usize, usize)> {
+    let (p, r) = find_part(parts, r, |s| s.get_row())?;
+    Some((p, r, c))
+}
+
+impl_stack_common!(HStack, locate_h);
+impl_stack_common!(VStack, locate_v);
+impl_stack_common!(HStackMut, locate_h);
+impl_stack_common!(VStackMut, locate_v);
+impl_stack_mut!(HStackMut, locate_h);
+impl_stack_mut!(VStackMut, locate_v);
+
+impl<'b, 'a, T> HStack<'b, 'a, T> {
+    pub fn row_iter(&self) -> HStackRows<'b, T, Slice2D<'a, T>> {
+        HStackRows::new(self.parts, self.row, self.col)
+    }
+}
+
+impl<'b, 'a, T> VStack<'b, 'a, T> {
+    pub fn row_iter(&self) -> VStackRows<'b, T, Slice2D<'a, T>> {
+        VStackRows::new(self.parts, self.row)
+    }
+}
+
+impl<'b, 'a, T> HStackMut<'b, 'a, T> {
+    pub fn row_iter(&self) -> HStackRows<'_, T, Slice2DMut<'a, T>> {
+        HStackRows::new(self.parts, self.row, self.col)
+    }
+    pub fn row_iter_mut(&mut self) -> HStackRowsMut<'_, T, Slice2DMut<'a, T>> {
+        HStackRowsMut::new(self.parts, self.row, self.col)
+    }
+}
+
+impl<'b, 'a, T> VStackMut<'b, 'a, T> {
+    pub fn row_iter(&self) -> VStackRows<'_, T, Slice2DMut<'a, T>> {
+        VStackRows::new(self.parts, self.row)
+    }
+    pub fn row_iter_mut(&mut self) -> VStackRowsMut<'_, T, Slice2DMut<'a, T>> {
+        VStackRowsMut::new(self.parts, self.row)
+    }
+}
+
+// rows of a `HStack`, each one chains the same row of every part. a row
+// spans several parts, so it is never a single slice and its elements are
+// handed out one by one, take the row slices of the parts themselves where
+// that matters
+pub struct HStackRows<'b, T, S> {
+    parts: &'b [S],
+    row: usize,
+    end: usize,
+    col: usize,
+    _marker: PhantomData<&'b T>,
+}
+
+impl<'b, T, S> HStackRows<'b, T, S>
+where
+    S: Shape2D + SlicePtr<T>,
+{
+    fn new(parts: &'b [S], row: usize, col: usize) -> HStackRows<'b, T, S> {
+        HStackRows {
+            parts,
+            row: 0,
+            end: row,
+            col,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'b, T, S> Iterator for HStackRows<'b, T, S>
+where
+    S: Shape2D + SlicePtr<T>,
+{
+    type Item = HStackRow<'b, T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row < self.end {
+            let row = HStackRow {
+                parts: self.parts,
+                row: self.row,
+                cur: None,
+                left: self.col,
+            };
+            self.row += 1;
+            Some(row)
+        } else {
+            None
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.end - self.row;
+        (n, Some(n))
+    }
+}
+impl<'b, T, S: Shape2D + SlicePtr<T>> ExactSizeIterator for HStackRows<'b, T, S> {}
+impl<'b, T, S: Shape2D + SlicePtr<T>> FusedIterator for HStackRows<'b, T, S> {}
+
+pub struct HStackRow<'b, T, S> {
+    parts: &'b [S],
+    row: usize,
+    cur: Option<Row<'b, T>>,
+    left: usize,
+}
+
+impl<'b, T, S> Iterator for HStackRow<'b, T, S>
+where
+    S: Shape2D + SlicePtr<T>,
+{
+    type Item = &'b T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(elem) = self.cur.as_mut().and_then(Iterator::next) {
+                self.left -= 1;
+                return Some(elem);
+            }
+            let (part, rest) = self.parts.split_first()?;
+            self.parts = rest;
+            self.cur = unsafe { Some(Row::new_unchecked(part, self.row)) };
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+}
+impl<'b, T, S: Shape2D + SlicePtr<T>> ExactSizeIterator for HStackRow<'b, T, S> {}
+impl<'b, T, S: Shape2D + SlicePtr<T>> FusedIterator for HStackRow<'b, T, S> {}
+
+pub struct HStackRowsMut<'b, T, S> {
+    parts: *mut S,
+    len: usize,
+    row: usize,
+    end: usize,
+    col: usize,
+    _marker: PhantomData<(&'b mut S, &'b mut T)>,
+}
+
+impl<'b, T, S> HStackRowsMut<'b, T, S>
+where
+    S: Shape2D + SlicePtrMut<T>,
+{
+    fn new(parts: &'b mut [S], row: usize, col: usize) -> HStackRowsMut<'b, T, S> {
+        HStackRowsMut {
+            parts: parts.as_mut_ptr(),
+            len: parts.len(),
+            row: 0,
+            end: row,
+            col,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'b, T, S> Iterator for HStackRowsMut<'b, T, S>
+where
+    S: Shape2D + SlicePtrMut<T>,
+{
+    type Item = HStackRowMut<'b, T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row < self.end {
+            let row = HStackRowMut {
+                parts: self.parts,
+                len: self.len,
+                row: self.row,
+                cur: None,
+                left: self.col,
+                _marker: PhantomData,
+            };
+            self.row += 1;
+            Some(row)
+        } else {
+            None
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.end - self.row;
+        (n, Some(n))
+    }
+}
+impl<'b, T, S: Shape2D + SlicePtrMut<T>> ExactSizeIterator for HStackRowsMut<'b, T, S> {}
+impl<'b, T, S: Shape2D + SlicePtrMut<T>> FusedIterator for HStackRowsMut<'b, T, S> {}
+
+pub struct HStackRowMut<'b, T, S> {
+    parts: *mut S,
+    len: usize,
+    row: usize,
+    cur: Option<RowMut<'b, T>>,
+    left: usize,
+    _marker: PhantomData<&'b mut S>,
+}
+
+impl<'b, T, S> Iterator for HStackRowMut<'b, T, S>
+where
+    S: Shape2D + SlicePtrMut<T>,
+{
+    type Item = &'b mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(elem) = self.cur.as_mut().and_then(Iterator::next) {
+                self.left -= 1;
+                return Some(elem);
+            }
+            if self.len == 0 {
+                return None;
+            }
+            // every row only hands out its own elements of the parts
+            let part = unsafe { &mut *self.parts };
+            self.parts = self.parts.wrapping_add(1);
+            self.len -= 1;
+            self.cur = unsafe { Some(RowMut::new_unchecked(part, self.row)) };
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+}
+impl<'b, T, S: Shape2D + SlicePtrMut<T>> ExactSizeIterator for HStackRowMut<'b, T, S> {}
+impl<'b, T, S: Shape2D + SlicePtrMut<T>> FusedIterator for HStackRowMut<'b, T, S> {}
+
+// rows of a `VStack`, the rows of every part one after another
+pub struct VStackRows<'b, T, S> {
+    parts: &'b [S],
+    row: usize,
+    left: usize,
+    _marker: PhantomData<&'b T>,
+}
+
+impl<'b, T, S> VStackRows<'b, T, S>
+where
+    S: Shape2D + SlicePtr<T>,
+{
+    fn new(parts: &'b [S], row: usize) -> VStackRows<'b, T, S> {
+        VStackRows {
+            parts,
+            row: 0,
+            left: row,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'b, T, S> Iterator for VStackRows<'b, T, S>
+where
+    S: Shape2D + SlicePtr<T>,
+{
+    type Item = Row<'b, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((part, rest)) = self.parts.split_first() {
+            if self.row < part.get_row() {
+                let row = unsafe { Row::new_unchecked(part, self.row) };
+                self.row += 1;
+                self.left -= 1;
+                return Some(row);
+            }
+            self.parts = rest;
+            self.row = 0;
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+}
+impl<'b, T, S: Shape2D + SlicePtr<T>> ExactSizeIterator for VStackRows<'b, T, S> {}
+impl<'b, T, S: Shape2D + SlicePtr<T>> FusedIterator for VStackRows<'b, T, S> {}
+
+pub struct VStackRowsMut<'b, T, S> {
+    parts: *mut S,
+    len: usize,
+    row: usize,
+    left: usize,
+    _marker: PhantomData<(&'b mut S, &'b mut T)>,
+}
+
+impl<'b, T, S> VStackRowsMut<'b, T, S>
+where
+    S: Shape2D + SlicePtrMut<T>,
+{
+    fn new(parts: &'b mut [S], row: usize) -> VStackRowsMut<'b, T, S> {
+        VStackRowsMut {
+            parts: parts.as_mut_ptr(),
+            len: parts.len(),
+            row: 0,
+            left: row,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'b, T, S> Iterator for VStackRowsMut<'b, T, S>
+where
+    S: Shape2D + SlicePtrMut<T>,
+{
+    type Item = RowMut<'b, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.len > 0 {
+            let part = unsafe { &mut *self.parts };
+            if self.row < part.get_row() {
+                let row = unsafe { RowMut::new_unchecked(part, self.row) };
+                self.row += 1;
+                self.left -= 1;
+                return Some(row);
+            }
+            self.parts = self.parts.wrapping_add(1);
+            self.len -= 1;
+            self.row = 0;
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+}
+impl<'b, T, S: Shape2D + SlicePtrMut<T>> ExactSizeIterator for VStackRowsMut<'b, T, S> {}
+impl<'b, T, S: Shape2D + SlicePtrMut<T>> FusedIterator for VStackRowsMut<'b, T, S> {}
+
+// comparisons, element by element in row-major order
+macro_rules! impl_stack_eq {
+    ($t:ident) => {
+        impl<'b, 'a, T, A> PartialEq<A> for $t<'b, 'a, T>
+        where
+            T: PartialEq,
+            A: Shape2D + SlicePtr<T>,
+        {
+            fn eq(&self, other: &A) -> bool {
+                self.get_shape() == (other.get_row(), other.get_col())
+                    && self
+                        .row_iter()
+                        .enumerate()
+                        .all(|(r, row)| row.eq(Row::new(other, r).unwrap()))
+            }
+        }
+    };
+    ($t:ident, $u:ident) => {
+        impl<'b, 'a, 'd, 'c, T> PartialEq<$u<'d, 'c, T>> for $t<'b, 'a, T>
+        where
+            T: PartialEq,
+        {
+            fn eq(&self, other: &$u<'d, 'c, T>) -> bool {
+                self.get_shape() == other.get_shape()
+                    && self.row_iter().zip(other.row_iter()).all(|(a, b)| a.eq(b))
+            }
+        }
+    };
+}
+
+impl_stack_eq!(HStack);
+impl_stack_eq!(VStack);
+impl_stack_eq!(HStackMut);
+impl_stack_eq!(VStackMut);
+impl_stack_eq!(HStack, HStack);
+impl_stack_eq!(HStack, VStack);
+impl_stack_eq!(HStack, HStackMut);
+impl_stack_eq!(HStack, VStackMut);
+impl_stack_eq!(VStack, HStack);
+impl_stack_eq!(VStack, VStack);
+impl_stack_eq!(VStack, HStackMut);
+impl_stack_eq!(VStack, VStackMut);
+impl_stack_eq!(HStackMut, HStack);
+impl_stack_eq!(HStackMut, VStack);
+impl_stack_eq!(HStackMut, HStackMut);
+impl_stack_eq!(HStackMut, VStackMut);
+impl_stack_eq!(VStackMut, HStack);
+impl_stack_eq!(VStackMut, VStack);
+impl_stack_eq!(VStackMut, HStackMut);
+impl_stack_eq!(VStackMut, VStackMut);
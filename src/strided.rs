@@ -0,0 +1,491 @@
+use crate::{
+    slice::{Shape2D, SlicePtr, SlicePtrMut},
+    utils::calc_2d_index,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterator},
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+    ptr::{self, NonNull},
+};
+
+// 1D view of `len` elements, `stride` apart from each other
+pub struct StridedSlice<'a, T> {
+    ptr: *const T,
+    len: usize,
+    stride: isize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> StridedSlice<'a, T> {
+    #[inline]
+    pub fn from_col<S>(slice_2d: &S, col: usize) -> Option<StridedSlice<'_, T>>
+    where
+        S: Shape2D + SlicePtr<T>,
+    {
+        if col < slice_2d.get_col() {
+            unsafe { Some(StridedSlice::from_col_unchecked(slice_2d, col)) }
+        } else {
+            None
+        }
+    }
+    /// # Safety
+    /// `col` must be less than the number of columns of `slice_2d`.
+    #[inline]
+    pub unsafe fn from_col_unchecked<S>(slice_2d: &S, col: usize) -> StridedSlice<'_, T>
+    where
+        S: Shape2D + SlicePtr<T>,
+    {
+        let ptr = slice_2d
+            .get_slice_ptr()
+            .offset(calc_2d_index(0, col, slice_2d));
+        StridedSlice::from_raw_parts(ptr, slice_2d.get_row(), slice_2d.get_row_stride())
+    }
+    /// # Safety
+    /// every `ptr + i * stride` with `i < len` must be valid for reads for `'b`
+    /// and not mutated during it.
+    #[inline]
+    pub unsafe fn from_raw_parts<'b>(
+        ptr: *const T,
+        len: usize,
+        stride: isize,
+    ) -> StridedSlice<'b, T> {
+        StridedSlice {
+            ptr,
+            len,
+            stride,
+            _marker: PhantomData,
+        }
+    }
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    #[inline]
+    pub fn stride(&self) -> isize {
+        self.stride
+    }
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&'a T> {
+        if i < self.len {
+            unsafe { Some(self.get_unchecked(i)) }
+        } else {
+            None
+        }
+    }
+    /// # Safety
+    /// `i` must be less than `len()`.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, i: usize) -> &'a T {
+        &*self.ptr.offset(i as isize * self.stride)
+    }
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            ptr: self.ptr,
+            len: self.len,
+            stride: self.stride,
+            _marker: PhantomData,
+        }
+    }
+    pub fn split_at(&self, mid: usize) -> (StridedSlice<'a, T>, StridedSlice<'a, T>) {
+        assert!(mid <= self.len, "out of range");
+        unsafe {
+            (
+                StridedSlice::from_raw_parts(self.ptr, mid, self.stride),
+                StridedSlice::from_raw_parts(
+                    self.ptr.wrapping_offset(mid as isize * self.stride),
+                    self.len - mid,
+                    self.stride,
+                ),
+            )
+        }
+    }
+    // the same elements in reverse order
+    #[inline]
+    pub fn rev(&self) -> StridedSlice<'a, T> {
+        unsafe {
+            StridedSlice::from_raw_parts(
+                last_ptr(self.ptr, self.len, self.stride),
+                self.len,
+                -self.stride,
+            )
+        }
+    }
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+#[inline(always)]
+fn last_ptr<T>(ptr: *const T, len: usize, stride: isize) -> *const T {
+    ptr.wrapping_offset(len.saturating_sub(1) as isize * stride)
+}
+
+impl<'a, T> Clone for StridedSlice<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T> Copy for StridedSlice<'a, T> {}
+
+impl<'a, T> Default for StridedSlice<'a, T> {
+    fn default() -> Self {
+        unsafe { StridedSlice::from_raw_parts(NonNull::dangling().as_ptr(), 0, 0) }
+    }
+}
+
+impl<'a, T> From<StridedSliceMut<'a, T>> for StridedSlice<'a, T> {
+    fn from(s: StridedSliceMut<'a, T>) -> Self {
+        unsafe { StridedSlice::from_raw_parts(s.ptr, s.len, s.stride) }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for StridedSlice<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T> Index<usize> for StridedSlice<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, i: usize) -> &T {
+        self.get(i).expect("out of range")
+    }
+}
+
+impl<'a, T> IntoIterator for StridedSlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct StridedSliceMut<'a, T> {
+    ptr: *mut T,
+    len: usize,
+    stride: isize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> StridedSliceMut<'a, T> {
+    #[inline]
+    pub fn from_col<S>(slice_2d: &mut S, col: usize) -> Option<StridedSliceMut<'_, T>>
+    where
+        S: Shape2D + SlicePtrMut<T>,
+    {
+        if col < slice_2d.get_col() {
+            unsafe { Some(StridedSliceMut::from_col_unchecked(slice_2d, col)) }
+        } else {
+            None
+        }
+    }
+    /// # Safety
+    /// `col` must be less than the number of columns of `slice_2d`.
+    #[inline]
+    pub unsafe fn from_col_unchecked<S>(slice_2d: &mut S, col: usize) -> StridedSliceMut<'_, T>
+    where
+        S: Shape2D + SlicePtrMut<T>,
+    {
+        let offset = calc_2d_index(0, col, slice_2d);
+        let ptr = slice_2d.get_slice_ptr_mut().offset(offset);
+        StridedSliceMut::from_raw_parts(ptr, slice_2d.get_row(), slice_2d.get_row_stride())
+    }
+    /// # Safety
+    /// every `ptr + i * stride` with `i < len` must be valid for reads and
+    /// writes for `'b`, not accessed through any other pointer during it, and
+    /// `stride` must not be zero if `len > 1`.
+    #[inline]
+    pub unsafe fn from_raw_parts<'b>(
+        ptr: *mut T,
+        len: usize,
+        stride: isize,
+    ) -> StridedSliceMut<'b, T> {
+        StridedSliceMut {
+            ptr,
+            len,
+            stride,
+            _marker: PhantomData,
+        }
+    }
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    #[inline]
+    pub fn stride(&self) -> isize {
+        self.stride
+    }
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i < self.len {
+            unsafe { Some(&*self.ptr.offset(i as isize * self.stride)) }
+        } else {
+            None
+        }
+    }
+    #[inline]
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i < self.len {
+            unsafe { Some(self.get_unchecked_mut(i)) }
+        } else {
+            None
+        }
+    }
+    /// # Safety
+    /// `i` must be less than `len()`.
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, i: usize) -> &mut T {
+        &mut *self.ptr.offset(i as isize * self.stride)
+    }
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            ptr: self.ptr,
+            len: self.len,
+            stride: self.stride,
+            _marker: PhantomData,
+        }
+    }
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            ptr: self.ptr,
+            len: self.len,
+            stride: self.stride,
+            _marker: PhantomData,
+        }
+    }
+    pub fn split_at_mut(&mut self, mid: usize) -> (StridedSliceMut<'_, T>, StridedSliceMut<'_, T>) {
+        assert!(mid <= self.len, "out of range");
+        unsafe {
+            (
+                StridedSliceMut::from_raw_parts(self.ptr, mid, self.stride),
+                StridedSliceMut::from_raw_parts(
+                    self.ptr.wrapping_offset(mid as isize * self.stride),
+                    self.len - mid,
+                    self.stride,
+                ),
+            )
+        }
+    }
+    #[inline]
+    pub fn rev(self) -> StridedSliceMut<'a, T> {
+        unsafe {
+            StridedSliceMut::from_raw_parts(
+                last_ptr(self.ptr, self.len, self.stride) as *mut T,
+                self.len,
+                -self.stride,
+            )
+        }
+    }
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        assert!(src.len() == self.len, "slice length does not match.");
+        self.iter_mut().zip(src).for_each(|(e, s)| *e = *s);
+    }
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.len && b < self.len, "out of range");
+        unsafe {
+            ptr::swap(
+                self.ptr.offset(a as isize * self.stride),
+                self.ptr.offset(b as isize * self.stride),
+            )
+        };
+    }
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.iter_mut().for_each(|e| *e = value.clone());
+    }
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.iter_mut().for_each(|e| *e = f());
+    }
+}
+
+impl<'a, T> Default for StridedSliceMut<'a, T> {
+    fn default() -> Self {
+        unsafe { StridedSliceMut::from_raw_parts(NonNull::dangling().as_ptr(), 0, 0) }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for StridedSliceMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T> Index<usize> for StridedSliceMut<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, i: usize) -> &T {
+        self.get(i).expect("out of range")
+    }
+}
+
+impl<'a, T> IndexMut<usize> for StridedSliceMut<'a, T> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        self.get_mut(i).expect("out of range")
+    }
+}
+
+impl<'a, T> IntoIterator for StridedSliceMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            ptr: self.ptr,
+            len: self.len,
+            stride: self.stride,
+            _marker: PhantomData,
+        }
+    }
+}
+
+// iterators
+pub struct Iter<'a, T> {
+    ptr: *const T,
+    len: usize,
+    stride: isize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len > 0 {
+            let elem = unsafe { &*self.ptr };
+            self.len -= 1;
+            self.ptr = self.ptr.wrapping_offset(self.stride);
+            Some(elem)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+    fn count(self) -> usize {
+        self.len
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let n = n.min(self.len);
+        self.len -= n;
+        self.ptr = self.ptr.wrapping_offset(n as isize * self.stride);
+        self.next()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len > 0 {
+            self.len -= 1;
+            unsafe { Some(&*self.ptr.offset(self.len as isize * self.stride)) }
+        } else {
+            None
+        }
+    }
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.len -= n.min(self.len);
+        self.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+pub struct IterMut<'a, T> {
+    ptr: *mut T,
+    len: usize,
+    stride: isize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len > 0 {
+            let elem = unsafe { &mut *self.ptr };
+            self.len -= 1;
+            self.ptr = self.ptr.wrapping_offset(self.stride);
+            Some(elem)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+    fn count(self) -> usize {
+        self.len
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let n = n.min(self.len);
+        self.len -= n;
+        self.ptr = self.ptr.wrapping_offset(n as isize * self.stride);
+        self.next()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len > 0 {
+            self.len -= 1;
+            unsafe { Some(&mut *self.ptr.offset(self.len as isize * self.stride)) }
+        } else {
+            None
+        }
+    }
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.len -= n.min(self.len);
+        self.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
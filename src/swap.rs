@@ -11,7 +11,7 @@ pub trait Slice2DSwap<T> {
     fn swap(&mut self, idx1: (usize, usize), idx2: (usize, usize));
 }
 
-impl<'a, T, S> Slice2DSwap<T> for S
+impl<T, S> Slice2DSwap<T> for S
 where
     S: Shape2D + SlicePtrMut<T>,
 {
@@ -25,13 +25,14 @@ where
                 && idx2.0 < self.get_row()
                 && idx2.1 < self.get_col()
             {
-                let ptr1 = self
-                    .get_slice_ptr_mut()
-                    .add(calc_2d_index(idx1.0, idx1.1, self));
-                let ptr2 = self
-                    .get_slice_ptr_mut()
-                    .add(calc_2d_index(idx2.0, idx2.1, self));
-                core::ptr::swap(ptr1, ptr2);
+                // both pointers come from one mutable borrow, taking a
+                // second one would invalidate the first
+                let (o1, o2) = (
+                    calc_2d_index(idx1.0, idx1.1, self),
+                    calc_2d_index(idx2.0, idx2.1, self),
+                );
+                let ptr = self.get_slice_ptr_mut();
+                core::ptr::swap(ptr.offset(o1), ptr.offset(o2));
             } else {
                 panic!("out of range");
             }
@@ -2,8 +2,40 @@ use crate::slice::Shape2D;
 use core::ops::{Bound, RangeBounds};
 
 #[inline(always)]
-pub fn calc_2d_index<S: Shape2D>(r: usize, c: usize, slice: &S) -> usize {
-    r * slice.get_base_col() + c
+pub fn calc_2d_index<S: Shape2D>(r: usize, c: usize, slice: &S) -> isize {
+    r as isize * slice.get_row_stride() + c as isize * slice.get_col_stride()
+}
+
+// whether every row lies in a contiguous part of the slice
+#[inline(always)]
+pub fn is_row_contiguous<S: Shape2D>(slice: &S) -> bool {
+    slice.get_col_stride() == 1 || slice.get_col() <= 1
+}
+
+// whether every column lies in a contiguous part of the slice
+#[inline(always)]
+pub fn is_col_contiguous<S: Shape2D>(slice: &S) -> bool {
+    slice.get_row_stride() == 1 || slice.get_row() <= 1
+}
+
+// whether the elements lie next to each other in row-major order, so that
+// they can be walked as one flat slice
+#[inline(always)]
+pub fn is_contiguous<S: Shape2D>(slice: &S) -> bool {
+    is_row_contiguous(slice)
+        && (slice.get_row_stride() == slice.get_col() as isize || slice.get_row() <= 1)
+}
+
+// length a slice needs to hold `row` rows of `col` elements, `row_stride`
+// apart and starting from `offset`, `None` if it overflows
+pub fn calc_strided_len(offset: usize, row: usize, col: usize, row_stride: usize) -> Option<usize> {
+    if row == 0 || col == 0 {
+        return Some(offset);
+    }
+    (row - 1)
+        .checked_mul(row_stride)?
+        .checked_add(col)?
+        .checked_add(offset)
 }
 
 pub fn calc_2d_range<B: RangeBounds<usize>>(len: usize, bound: &B) -> (usize, usize) {
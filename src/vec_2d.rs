@@ -1 +1,1048 @@
-// TODO
+use crate::{
+    cmp::slice_2d_eq,
+    index::{GetElemRef, Slice2DIndex, Slice2DIndexMut},
+    iter::Slice2DIter,
+    slice::{Shape2D, Shape2DExt, Slice2D, Slice2DMut, SlicePtr, SlicePtrMut},
+    strided::{StridedSlice, StridedSliceMut},
+    utils::calc_2d_range,
+};
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error, realloc, Layout},
+    vec::{self, Vec},
+};
+use core::{
+    cmp,
+    convert::From,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ops::{Index, IndexMut, RangeBounds},
+    ptr::{self, NonNull},
+    slice,
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TryReserveError {
+    CapacityOverflow,
+    AllocError { layout: Layout },
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryReserveError::CapacityOverflow => f.write_str("capacity overflow"),
+            TryReserveError::AllocError { .. } => f.write_str("memory allocation failed"),
+        }
+    }
+}
+
+fn handle_reserve(result: Result<(), TryReserveError>) {
+    match result {
+        Ok(()) => {}
+        Err(TryReserveError::CapacityOverflow) => panic!("capacity overflow"),
+        Err(TryReserveError::AllocError { layout }) => handle_alloc_error(layout),
+    }
+}
+
+// uninitialized buffer, it only manages the allocation and never drops elements
+struct RawBuf<T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    // alignment of the allocation, at least `align_of::<T>()`
+    align: usize,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for RawBuf<T> {}
+unsafe impl<T: Sync> Sync for RawBuf<T> {}
+
+impl<T> RawBuf<T> {
+    fn new() -> RawBuf<T> {
+        RawBuf::with_align(mem::align_of::<T>())
+    }
+    fn with_align(align: usize) -> RawBuf<T> {
+        RawBuf {
+            ptr: NonNull::dangling(),
+            cap: if mem::size_of::<T>() == 0 {
+                usize::MAX
+            } else {
+                0
+            },
+            align,
+            _marker: PhantomData,
+        }
+    }
+    fn try_with_capacity(cap: usize, align: usize) -> Result<RawBuf<T>, TryReserveError> {
+        let mut buf = RawBuf::with_align(align);
+        buf.try_resize(cap)?;
+        Ok(buf)
+    }
+    fn layout(&self, cap: usize) -> Result<Layout, TryReserveError> {
+        Layout::array::<T>(cap)
+            .and_then(|layout| layout.align_to(self.align))
+            .map_err(|_| TryReserveError::CapacityOverflow)
+    }
+    // elements in `..cap.min(self.cap)` are preserved
+    fn try_resize(&mut self, cap: usize) -> Result<(), TryReserveError> {
+        if mem::size_of::<T>() == 0 || cap == self.cap {
+            return Ok(());
+        }
+        if cap == 0 {
+            unsafe { dealloc(self.ptr.as_ptr() as *mut u8, self.layout(self.cap).unwrap()) };
+            self.ptr = NonNull::dangling();
+            self.cap = 0;
+            return Ok(());
+        }
+        let layout = self.layout(cap)?;
+        let ptr = unsafe {
+            if self.cap == 0 {
+                alloc(layout)
+            } else {
+                realloc(
+                    self.ptr.as_ptr() as *mut u8,
+                    self.layout(self.cap).unwrap(),
+                    layout.size(),
+                )
+            }
+        };
+        self.ptr = NonNull::new(ptr as *mut T).ok_or(TryReserveError::AllocError { layout })?;
+        self.cap = cap;
+        Ok(())
+    }
+}
+
+impl<T> Drop for RawBuf<T> {
+    fn drop(&mut self) {
+        if mem::size_of::<T>() != 0 && self.cap != 0 {
+            unsafe { dealloc(self.ptr.as_ptr() as *mut u8, self.layout(self.cap).unwrap()) };
+        }
+    }
+}
+
+// Row `r` starts at `r * base_col`, cells between `col` and `base_col`
+// of each row are reserved for new columns and are left uninitialized.
+// `base_col` is kept a multiple of `pitch_step(align)`, so that every row
+// starts at the alignment of the buffer.
+pub struct Vec2D<T> {
+    buf: RawBuf<T>,
+
+    base_col: usize,
+    row: usize,
+    col: usize,
+}
+
+impl<T> Vec2D<T> {
+    pub fn new() -> Vec2D<T> {
+        Vec2D {
+            buf: RawBuf::new(),
+            base_col: 0,
+            row: 0,
+            col: 0,
+        }
+    }
+    pub fn with_capacity(row: usize, col: usize) -> Vec2D<T> {
+        Vec2D::with_capacity_aligned(row, col, mem::align_of::<T>())
+    }
+    // every row starts at an address aligned to `align` bytes
+    pub fn with_capacity_aligned(row: usize, col: usize, align: usize) -> Vec2D<T> {
+        assert!(align.is_power_of_two(), "alignment is not a power of two.");
+        let mut v = Vec2D::new();
+        v.buf = RawBuf::with_align(cmp::max(align, mem::align_of::<T>()));
+        handle_reserve(v.try_relayout(row, col, v.buf.align));
+        v
+    }
+    pub fn from_vec(data: Vec<T>, row: usize, col: usize) -> Vec2D<T> {
+        assert!(
+            row.checked_mul(col) == Some(data.len()),
+            "vec does not match the shape."
+        );
+        // `Vec` allocates with the same layout, so its buffer is reused as is
+        let mut data = ManuallyDrop::new(data);
+        let mut buf = RawBuf::new();
+        unsafe { buf.ptr = NonNull::new_unchecked(data.as_mut_ptr()) };
+        if mem::size_of::<T>() != 0 {
+            buf.cap = data.capacity();
+        }
+        Vec2D {
+            buf,
+            base_col: col,
+            row,
+            col,
+        }
+    }
+    pub fn from_elem(row: usize, col: usize, elem: T) -> Vec2D<T>
+    where
+        T: Clone,
+    {
+        Vec2D::from_fn(row, col, |_| elem.clone())
+    }
+    pub fn from_fn<F>(row: usize, col: usize, f: F) -> Vec2D<T>
+    where
+        F: FnMut((usize, usize)) -> T,
+    {
+        Vec2D::from_fn_aligned(row, col, mem::align_of::<T>(), f)
+    }
+    pub fn from_fn_aligned<F>(row: usize, col: usize, align: usize, f: F) -> Vec2D<T>
+    where
+        F: FnMut((usize, usize)) -> T,
+    {
+        let mut v = Vec2D::with_capacity_aligned(row, col, align);
+        v.col = col;
+        v.extend_rows_with(row, f);
+        v
+    }
+
+    #[inline]
+    pub fn row_capacity(&self) -> usize {
+        self.buf
+            .cap
+            .checked_div(self.base_col)
+            .unwrap_or(usize::MAX)
+    }
+    #[inline]
+    pub fn col_capacity(&self) -> usize {
+        self.base_col
+    }
+    #[inline]
+    pub fn align(&self) -> usize {
+        self.buf.align
+    }
+    // distance in bytes between the starts of two adjacent rows
+    #[inline]
+    pub fn pitch(&self) -> usize {
+        self.base_col * mem::size_of::<T>()
+    }
+    // smallest number of elements whose size is a multiple of `align`
+    fn pitch_step(align: usize) -> usize {
+        let size = mem::size_of::<T>();
+        if size == 0 {
+            1
+        } else {
+            align >> cmp::min(size.trailing_zeros(), align.trailing_zeros())
+        }
+    }
+    pub fn reserve_rows(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve((additional, 0)));
+    }
+    pub fn reserve_cols(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve((0, additional)));
+    }
+    pub fn try_reserve(&mut self, additional: (usize, usize)) -> Result<(), TryReserveError> {
+        let row = self
+            .row
+            .checked_add(additional.0)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        let col = self
+            .col
+            .checked_add(additional.1)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        let (row_cap, col_cap) = (self.row_capacity(), self.col_capacity());
+        if row <= row_cap && col <= col_cap {
+            return Ok(());
+        }
+        // only grow the dimension which runs out of space, amortized
+        let row_cap = if self.base_col == 0 || mem::size_of::<T>() == 0 {
+            row
+        } else if row <= row_cap {
+            row_cap
+        } else {
+            cmp::max(row, row_cap.saturating_mul(2))
+        };
+        let col_cap = if col <= col_cap {
+            col_cap
+        } else {
+            cmp::max(col, col_cap.saturating_mul(2))
+        };
+        self.try_relayout(row_cap, col_cap, self.buf.align)
+    }
+    pub fn shrink_to_fit(&mut self) {
+        handle_reserve(self.try_relayout(self.row, self.col, self.buf.align));
+    }
+    // move data into a buffer of `row_cap` rows with a pitch of `col_cap`,
+    // whose rows are aligned to `align`
+    fn try_relayout(
+        &mut self,
+        row_cap: usize,
+        col_cap: usize,
+        align: usize,
+    ) -> Result<(), TryReserveError> {
+        debug_assert!(row_cap >= self.row && col_cap >= self.col);
+        let col_cap = col_cap
+            .checked_next_multiple_of(Self::pitch_step(align))
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        let cap = row_cap
+            .checked_mul(col_cap)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if mem::size_of::<T>() == 0 {
+            self.buf.align = align;
+            self.base_col = col_cap;
+        } else if col_cap == self.base_col && align == self.buf.align {
+            self.buf.try_resize(cap)?;
+        } else {
+            let buf = RawBuf::<T>::try_with_capacity(cap, align)?;
+            for r in 0..self.row {
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        self.buf.ptr.as_ptr().add(r * self.base_col),
+                        buf.ptr.as_ptr().add(r * col_cap),
+                        self.col,
+                    )
+                };
+            }
+            self.buf = buf;
+            self.base_col = col_cap;
+        }
+        Ok(())
+    }
+
+    pub fn resize(&mut self, row: usize, col: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.resize_with(row, col, || value.clone())
+    }
+    pub fn resize_with<F>(&mut self, row: usize, col: usize, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.truncate_rows(row);
+        self.truncate_cols(col);
+        self.reserve_cols(col - self.col);
+        // new cells of existing rows are in the reserved space already,
+        // so nothing needs to be moved
+        if col > self.col {
+            for r in 0..self.row {
+                for c in self.col..col {
+                    unsafe { ptr::write(self.ptr_at(r, c), f()) };
+                }
+            }
+            self.col = col;
+        }
+        self.extend_rows_with(row - self.row, |_| f());
+    }
+
+    // append `n` rows, cells are written in row-major order
+    fn extend_rows_with<F>(&mut self, n: usize, mut f: F)
+    where
+        F: FnMut((usize, usize)) -> T,
+    {
+        self.reserve_rows(n);
+        for _ in 0..n {
+            let r = self.row;
+            for c in 0..self.col {
+                // elements of an unfinished row are leaked on panic
+                unsafe { ptr::write(self.ptr_at(r, c), f((r, c))) };
+            }
+            self.row += 1;
+        }
+    }
+    #[inline(always)]
+    unsafe fn ptr_at(&self, r: usize, c: usize) -> *mut T {
+        self.buf.ptr.as_ptr().add(r * self.base_col + c)
+    }
+
+    pub fn push_row(&mut self, row: &[T])
+    where
+        T: Clone,
+    {
+        self.insert_row(self.row, row);
+    }
+    pub fn push_col<I>(&mut self, col: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.insert_col(self.col, col);
+    }
+    pub fn insert_row(&mut self, i: usize, row: &[T])
+    where
+        T: Clone,
+    {
+        self.insert_rows_from(i, Slice2D::from_slice(row, 1, row.len()));
+    }
+    pub fn insert_rows_from(&mut self, i: usize, rows: Slice2D<'_, T>)
+    where
+        T: Clone,
+    {
+        assert!(i <= self.row, "out of range");
+        if rows.get_row() == 0 {
+            return;
+        }
+        if self.row == 0 {
+            self.reserve_cols(rows.get_col().saturating_sub(self.col));
+            self.col = rows.get_col();
+        }
+        assert!(rows.get_col() == self.col, "row length does not match.");
+
+        // append the new rows, then rotate them into place
+        let row = self.row;
+        self.extend_rows_with(rows.get_row(), |(r, c)| unsafe {
+            rows.get_unchecked((r - row, c)).clone()
+        });
+        unsafe {
+            let tail = slice::from_raw_parts_mut(
+                self.ptr_at(i, 0) as *mut MaybeUninit<T>,
+                (self.row - i) * self.base_col,
+            );
+            tail.rotate_right(rows.get_row() * self.base_col);
+        }
+    }
+    pub fn insert_col<I>(&mut self, j: usize, col: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        assert!(j <= self.col, "out of range");
+        let mut col = col.into_iter().collect::<Vec<_>>();
+        if self.row == 0 && self.col == 0 {
+            // the column decides the number of rows of an empty grid
+            self.reserve_cols(1);
+            self.col = 1;
+            let mut col = col.into_iter();
+            self.extend_rows_with(col.len(), |_| col.next().unwrap());
+            return;
+        }
+        assert!(col.len() == self.row, "column length does not match.");
+
+        // nothing panics once the rows start moving
+        self.reserve_cols(1);
+        unsafe {
+            col.set_len(0);
+            for r in 0..self.row {
+                let p = self.ptr_at(r, j);
+                ptr::copy(p, p.add(1), self.col - j);
+                ptr::copy_nonoverlapping(col.as_ptr().add(r), p, 1);
+            }
+        }
+        self.col += 1;
+    }
+    pub fn remove_row(&mut self, i: usize) -> Vec<T> {
+        assert!(i < self.row, "out of range");
+        let mut row = Vec::with_capacity(self.col);
+        unsafe {
+            ptr::copy_nonoverlapping(self.ptr_at(i, 0), row.as_mut_ptr(), self.col);
+            row.set_len(self.col);
+            ptr::copy(
+                self.ptr_at(i + 1, 0),
+                self.ptr_at(i, 0),
+                (self.row - i - 1) * self.base_col,
+            );
+        }
+        self.row -= 1;
+        row
+    }
+    pub fn remove_col(&mut self, j: usize) -> Vec<T> {
+        assert!(j < self.col, "out of range");
+        let mut col = Vec::with_capacity(self.row);
+        for r in 0..self.row {
+            unsafe {
+                let p = self.ptr_at(r, j);
+                col.push(ptr::read(p));
+                ptr::copy(p.add(1), p, self.col - j - 1);
+            }
+        }
+        self.col -= 1;
+        col
+    }
+    pub fn retain_rows<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&[T]) -> bool,
+    {
+        self.retain_by(Axis::Row, |v, r| unsafe {
+            f(slice::from_raw_parts(v.ptr_at(r, 0), v.col))
+        });
+    }
+    pub fn retain_rows_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut [T]) -> bool,
+    {
+        self.retain_by(Axis::Row, |v, r| unsafe {
+            f(slice::from_raw_parts_mut(v.ptr_at(r, 0), v.col))
+        });
+    }
+    pub fn retain_cols<F>(&mut self, mut f: F)
+    where
+        F: FnMut(StridedSlice<'_, T>) -> bool,
+    {
+        self.retain_by(Axis::Col, |v, c| unsafe {
+            f(StridedSlice::from_col_unchecked(v, c))
+        });
+    }
+    pub fn retain_cols_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(StridedSliceMut<'_, T>) -> bool,
+    {
+        self.retain_by(Axis::Col, |v, c| unsafe {
+            f(StridedSliceMut::from_col_unchecked(v, c))
+        });
+    }
+    // `f` is called with the index of every row or column in order,
+    // while those not processed yet are hidden from the `Vec2D`
+    fn retain_by<F>(&mut self, axis: Axis, mut f: F)
+    where
+        F: FnMut(&mut Vec2D<T>, usize) -> bool,
+    {
+        let len = match axis {
+            Axis::Row => mem::replace(&mut self.row, 0),
+            Axis::Col => mem::replace(&mut self.col, 0),
+        };
+        let mut g = RetainGuard {
+            vec: self,
+            axis,
+            len,
+            processed: 0,
+            deleted: 0,
+        };
+        while g.processed < len {
+            let i = g.processed;
+            if !f(g.vec, i) {
+                // count it first, so a panicking destructor does not
+                // get it dropped or moved again
+                g.processed += 1;
+                g.deleted += 1;
+                unsafe { g.drop_at(i) };
+                continue;
+            }
+            if g.deleted > 0 {
+                unsafe { g.shift(i, 1) };
+            }
+            g.processed += 1;
+        }
+    }
+
+    pub fn truncate_rows(&mut self, row: usize) {
+        if row < self.row {
+            // shrink first, so that a panicking destructor leaks the rest
+            // instead of dropping them twice
+            let old_row = mem::replace(&mut self.row, row);
+            for r in row..old_row {
+                unsafe {
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr_at(r, 0), self.col))
+                };
+            }
+        }
+    }
+    pub fn truncate_cols(&mut self, col: usize) {
+        if col < self.col {
+            let old_col = mem::replace(&mut self.col, col);
+            for r in 0..self.row {
+                unsafe {
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                        self.ptr_at(r, col),
+                        old_col - col,
+                    ))
+                };
+            }
+        }
+    }
+
+    // rows of an aligned grid can only be packed if their size keeps them
+    // aligned, otherwise they are moved to a new buffer of the natural
+    // alignment of `T`, which `align()` reports from then on
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.row > 1 && self.base_col != self.col {
+            if self.col % Self::pitch_step(self.buf.align) == 0 {
+                self.pack_rows();
+            } else {
+                handle_reserve(self.try_relayout(self.row, self.col, mem::align_of::<T>()));
+            }
+        }
+        unsafe { slice::from_raw_parts_mut(self.buf.ptr.as_ptr(), self.row * self.col) }
+    }
+    // move the rows next to each other in place, `base_col` may no longer
+    // be a multiple of the pitch step afterwards
+    fn pack_rows(&mut self) {
+        for r in 1..self.row {
+            unsafe { ptr::copy(self.ptr_at(r, 0), self.ptr_at(0, r * self.col), self.col) };
+        }
+        self.base_col = self.col;
+    }
+    // the permutation is walked over packed rows, padded rows of an aligned
+    // grid are restored in place afterwards
+    pub fn transpose_in_place(&mut self) {
+        let (row, col) = self.get_shape();
+        self.pack_rows();
+        let len = row * col;
+        if row > 1 && col > 1 && mem::size_of::<T>() != 0 {
+            // element at `r * col + c` goes to `c * row + r`, the first
+            // and the last one stay where they are. every cycle of the
+            // permutation is walked once, a bit per element records
+            // which ones have been moved already
+            const BITS: usize = usize::BITS as usize;
+            let mut moved = alloc::vec![0usize; len.div_ceil(BITS)];
+            let dest = |i: usize| (i % col) * row + i / col;
+            let p = self.buf.ptr.as_ptr();
+            for start in 1..len - 1 {
+                if moved[start / BITS] & (1 << (start % BITS)) != 0 {
+                    continue;
+                }
+                unsafe {
+                    let mut elem = ptr::read(p.add(start));
+                    let mut i = dest(start);
+                    loop {
+                        moved[i / BITS] |= 1 << (i % BITS);
+                        elem = ptr::replace(p.add(i), elem);
+                        if i == start {
+                            mem::forget(elem);
+                            break;
+                        }
+                        i = dest(i);
+                    }
+                }
+            }
+        }
+        self.row = col;
+        self.col = row;
+        self.base_col = row;
+        handle_reserve(self.try_restore_pitch());
+    }
+    // spread packed rows out to a multiple of the pitch step, from the last
+    // row backwards so that no row is overwritten before it has moved. the
+    // buffer only grows if it is too small for the padding
+    fn try_restore_pitch(&mut self) -> Result<(), TryReserveError> {
+        let pitch = self
+            .col
+            .checked_next_multiple_of(Self::pitch_step(self.buf.align))
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if pitch == self.base_col {
+            return Ok(());
+        }
+        let cap = self
+            .row
+            .checked_mul(pitch)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if cap > self.buf.cap {
+            self.buf.try_resize(cap)?;
+        }
+        for r in (1..self.row).rev() {
+            unsafe {
+                ptr::copy(
+                    self.ptr_at(r, 0),
+                    self.buf.ptr.as_ptr().add(r * pitch),
+                    self.col,
+                )
+            };
+        }
+        self.base_col = pitch;
+        Ok(())
+    }
+    pub fn into_flat_vec(self) -> (Vec<T>, (usize, usize)) {
+        if self.buf.align != mem::align_of::<T>() {
+            // `Vec` can only take over buffers of the natural alignment
+            let v = ManuallyDrop::new(self);
+            let buf = unsafe { ptr::read(&v.buf) };
+            let mut data = Vec::<T>::with_capacity(v.row * v.col);
+            for r in 0..v.row {
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        v.ptr_at(r, 0),
+                        data.as_mut_ptr().add(r * v.col),
+                        v.col,
+                    )
+                };
+            }
+            unsafe { data.set_len(v.row * v.col) };
+            drop(buf);
+            return (data, v.get_shape());
+        }
+        let mut v = ManuallyDrop::new(self);
+        v.make_contiguous();
+        let buf = ManuallyDrop::new(unsafe { ptr::read(&v.buf) });
+        let data = unsafe { Vec::from_raw_parts(buf.ptr.as_ptr(), v.row * v.col, buf.cap) };
+        (data, v.get_shape())
+    }
+    pub fn into_rows(self) -> IntoRows<T> {
+        let (data, (row, col)) = self.into_flat_vec();
+        IntoRows {
+            data: data.into_iter(),
+            row,
+            col,
+        }
+    }
+    pub fn drain_rows<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = calc_2d_range(self.row, &range);
+        assert!(start <= end && end <= self.row, "out of range");
+        Drain::new(self, Axis::Row, start, end)
+    }
+    pub fn drain_cols<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = calc_2d_range(self.col, &range);
+        assert!(start <= end && end <= self.col, "out of range");
+        Drain::new(self, Axis::Col, start, end)
+    }
+
+    // `None` if the rows are padded, see `make_contiguous`
+    #[inline]
+    pub fn as_slice(&self) -> Option<&[T]> {
+        if self.row <= 1 || self.base_col == self.col {
+            unsafe {
+                Some(slice::from_raw_parts(
+                    self.buf.ptr.as_ptr(),
+                    self.row * self.col,
+                ))
+            }
+        } else {
+            None
+        }
+    }
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.make_contiguous()
+    }
+    #[inline]
+    pub fn as_slice_2d(&self) -> Slice2D<'_, T> {
+        unsafe { Slice2D::from_raw_parts(self.buf.ptr.as_ptr(), self.base_col, self.row, self.col) }
+    }
+    #[inline]
+    pub fn as_slice_2d_mut(&mut self) -> Slice2DMut<'_, T> {
+        unsafe {
+            Slice2DMut::from_raw_parts(self.buf.ptr.as_ptr(), self.base_col, self.row, self.col)
+        }
+    }
+}
+
+impl<T> Drop for Vec2D<T> {
+    fn drop(&mut self) {
+        self.truncate_rows(0);
+    }
+}
+
+impl<T> IntoIterator for Vec2D<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_flat_vec().0.into_iter()
+    }
+}
+
+impl<T> Default for Vec2D<T> {
+    fn default() -> Self {
+        Vec2D::new()
+    }
+}
+
+impl<T: Clone> Clone for Vec2D<T> {
+    fn clone(&self) -> Self {
+        Vec2D::from_fn_aligned(self.row, self.col, self.buf.align, |idx| unsafe {
+            self.get_unchecked(idx).clone()
+        })
+    }
+}
+
+impl<T: Hash> Hash for Vec2D<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get_shape().hash(state);
+        for row in self.row_slice_iter() {
+            row.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Vec2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.row_slice_iter()).finish()
+    }
+}
+
+impl<T> Shape2D for Vec2D<T> {
+    #[inline(always)]
+    fn get_row_stride(&self) -> isize {
+        self.base_col as isize
+    }
+
+    #[inline(always)]
+    fn get_col_stride(&self) -> isize {
+        1
+    }
+
+    #[inline(always)]
+    fn get_row(&self) -> usize {
+        self.row
+    }
+
+    #[inline(always)]
+    fn get_col(&self) -> usize {
+        self.col
+    }
+}
+impl<T> SlicePtr<T> for Vec2D<T> {
+    fn get_slice_ptr(&self) -> *const T {
+        self.buf.ptr.as_ptr()
+    }
+}
+impl<T> SlicePtrMut<T> for Vec2D<T> {
+    fn get_slice_ptr_mut(&mut self) -> *mut T {
+        self.buf.ptr.as_ptr()
+    }
+}
+
+impl<'a, T> From<&'a Vec2D<T>> for Slice2D<'a, T> {
+    fn from(v: &'a Vec2D<T>) -> Self {
+        v.as_slice_2d()
+    }
+}
+impl<'a, T> From<&'a mut Vec2D<T>> for Slice2DMut<'a, T> {
+    fn from(v: &'a mut Vec2D<T>) -> Self {
+        v.as_slice_2d_mut()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Vec2D<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: (usize, usize)) -> &T {
+        Slice2DIndex::index(index, self)
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Vec2D<T> {
+    #[inline]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        Slice2DIndexMut::index_mut(index, self)
+    }
+}
+
+impl<T, A> PartialEq<A> for Vec2D<T>
+where
+    T: PartialEq,
+    A: Shape2D + SlicePtr<T> + Slice2DIter<T, A>,
+{
+    fn eq(&self, other: &A) -> bool {
+        slice_2d_eq(self, other)
+    }
+}
+impl<T: Eq> Eq for Vec2D<T> {}
+
+pub struct IntoRows<T> {
+    data: vec::IntoIter<T>,
+    row: usize,
+    col: usize,
+}
+
+impl<T> Iterator for IntoRows<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row > 0 {
+            self.row -= 1;
+            Some(self.data.by_ref().take(self.col).collect())
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.row, Some(self.row))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoRows<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.row > 0 {
+            self.row -= 1;
+            let mut row = self.data.by_ref().rev().take(self.col).collect::<Vec<_>>();
+            row.reverse();
+            Some(row)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoRows<T> {}
+impl<T> FusedIterator for IntoRows<T> {}
+
+enum Axis {
+    Row,
+    Col,
+}
+
+// Move rows or columns not processed yet backward to close the gap left
+// by deleted ones, even if `retain_by` is interrupted by a panic.
+struct RetainGuard<'a, T> {
+    vec: &'a mut Vec2D<T>,
+    axis: Axis,
+    len: usize,
+    processed: usize,
+    deleted: usize,
+}
+
+impl<'a, T> RetainGuard<'a, T> {
+    unsafe fn drop_at(&mut self, i: usize) {
+        match self.axis {
+            Axis::Row => ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.vec.ptr_at(i, 0),
+                self.vec.col,
+            )),
+            Axis::Col => {
+                for r in 0..self.vec.row {
+                    ptr::drop_in_place(self.vec.ptr_at(r, i));
+                }
+            }
+        }
+    }
+    // move `n` rows or columns starting from `i` backward by `self.deleted`
+    unsafe fn shift(&mut self, i: usize, n: usize) {
+        let d = self.deleted;
+        match self.axis {
+            Axis::Row => ptr::copy(
+                self.vec.ptr_at(i, 0),
+                self.vec.ptr_at(i - d, 0),
+                n * self.vec.base_col,
+            ),
+            Axis::Col => {
+                for r in 0..self.vec.row {
+                    ptr::copy(self.vec.ptr_at(r, i), self.vec.ptr_at(r, i - d), n);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T> Drop for RetainGuard<'a, T> {
+    fn drop(&mut self) {
+        if self.deleted > 0 {
+            unsafe { self.shift(self.processed, self.len - self.processed) };
+        }
+        let len = self.len - self.deleted;
+        match self.axis {
+            Axis::Row => self.vec.row = len,
+            Axis::Col => self.vec.col = len,
+        }
+    }
+}
+
+// While draining, the band and everything behind it along `axis` is
+// hidden from the `Vec2D`, so forgetting a `Drain` only leaks elements.
+pub struct Drain<'a, T> {
+    vec: &'a mut Vec2D<T>,
+    axis: Axis,
+    start: usize,
+    end: usize,
+
+    row: usize,
+    col: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Drain<'a, T> {
+    fn new(vec: &'a mut Vec2D<T>, axis: Axis, start: usize, end: usize) -> Drain<'a, T> {
+        let (row, col) = vec.get_shape();
+        let len = match axis {
+            Axis::Row => {
+                vec.row = start;
+                (end - start) * col
+            }
+            Axis::Col => {
+                vec.col = start;
+                row * (end - start)
+            }
+        };
+        Drain {
+            vec,
+            axis,
+            start,
+            end,
+            row,
+            col,
+            front: 0,
+            back: len,
+        }
+    }
+    #[inline]
+    fn ptr_at(&self, i: usize) -> *mut T {
+        unsafe {
+            match self.axis {
+                Axis::Row => self.vec.ptr_at(self.start + i / self.col, i % self.col),
+                Axis::Col => {
+                    let width = self.end - self.start;
+                    self.vec.ptr_at(i / width, self.start + i % width)
+                }
+            }
+        }
+    }
+    fn close_gap(&mut self) {
+        let width = self.end - self.start;
+        match self.axis {
+            Axis::Row => {
+                unsafe {
+                    ptr::copy(
+                        self.vec.ptr_at(self.end, 0),
+                        self.vec.ptr_at(self.start, 0),
+                        (self.row - self.end) * self.vec.base_col,
+                    )
+                };
+                self.vec.row = self.row - width;
+            }
+            Axis::Col => {
+                for r in 0..self.row {
+                    unsafe {
+                        ptr::copy(
+                            self.vec.ptr_at(r, self.end),
+                            self.vec.ptr_at(r, self.start),
+                            self.col - self.end,
+                        )
+                    };
+                }
+                self.vec.col = self.col - width;
+            }
+        }
+    }
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let elem = unsafe { ptr::read(self.ptr_at(self.front)) };
+            self.front += 1;
+            Some(elem)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(unsafe { ptr::read(self.ptr_at(self.back)) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {}
+impl<'a, T> FusedIterator for Drain<'a, T> {}
+
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        // close the gap even if dropping the rest of the band panics
+        struct CloseGapOnDrop<'r, 'a, T>(&'r mut Drain<'a, T>);
+
+        impl<'r, 'a, T> Drop for CloseGapOnDrop<'r, 'a, T> {
+            fn drop(&mut self) {
+                self.0.close_gap();
+            }
+        }
+
+        let guard = CloseGapOnDrop(self);
+        guard.0.for_each(drop);
+    }
+}
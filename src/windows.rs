@@ -0,0 +1,217 @@
+use crate::{
+    iter::IndexCursor,
+    padded::Padded,
+    slice::{Shape2D, Slice2D, SlicePtr},
+};
+use core::{
+    iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterator},
+    marker::PhantomData,
+};
+
+// Overlapping sub-views of `shape` rows and columns, with their top left
+// corners `step` apart, in row-major or column-major order of the corners.
+pub trait Slice2DWindows<T>: Shape2D + SlicePtr<T> {
+    fn windows(&self, shape: (usize, usize)) -> Windows<'_, T>;
+    fn windows_with_step(&self, shape: (usize, usize), step: (usize, usize)) -> Windows<'_, T>;
+    fn col_major_windows(&self, shape: (usize, usize)) -> Windows<'_, T>;
+    fn col_major_windows_with_step(
+        &self,
+        shape: (usize, usize),
+        step: (usize, usize),
+    ) -> Windows<'_, T>;
+}
+
+// number of windows of `size` cells, `step` apart, on an axis of `len`
+// cells
+#[inline]
+fn calc_window_count(len: usize, size: usize, step: usize) -> usize {
+    assert!(size != 0, "window size is zero.");
+    if size <= len {
+        (len - size) / step + 1
+    } else {
+        0
+    }
+}
+
+pub struct Windows<'a, T> {
+    ptr: *const T,
+    row_stride: isize,
+    col_stride: isize,
+    shape: (usize, usize),
+    // walks the top left corners
+    cursor: IndexCursor,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Windows<'a, T> {
+    pub fn new<S>(
+        slice_2d: &S,
+        shape: (usize, usize),
+        step: (usize, usize),
+        col_major: bool,
+    ) -> Windows<'_, T>
+    where
+        S: Shape2D + SlicePtr<T>,
+    {
+        assert!(step.0 != 0 && step.1 != 0, "step is zero.");
+        let (row, col) = (slice_2d.get_row(), slice_2d.get_col());
+        // a step past the end leaves at most one window, clamping it keeps
+        // the cursor stride from overflowing
+        let step = (step.0.min(row.max(1)), step.1.min(col.max(1)));
+        let (row_stride, col_stride) = (slice_2d.get_row_stride(), slice_2d.get_col_stride());
+        Windows {
+            ptr: slice_2d.get_slice_ptr(),
+            row_stride,
+            col_stride,
+            shape,
+            cursor: IndexCursor::from_parts(
+                calc_window_count(row, shape.0, step.0),
+                calc_window_count(col, shape.1, step.1),
+                row_stride * step.0 as isize,
+                col_stride * step.1 as isize,
+                col_major,
+            ),
+            _marker: PhantomData,
+        }
+    }
+    #[inline(always)]
+    fn window(&self, offset: isize) -> Slice2D<'a, T> {
+        unsafe {
+            Slice2D::from_strided_raw_parts(
+                self.ptr.offset(offset),
+                self.row_stride,
+                self.col_stride,
+                self.shape.0,
+                self.shape.1,
+            )
+        }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = Slice2D<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, offset) = self.cursor.next()?;
+        Some(self.window(offset))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.len(), Some(self.cursor.len()))
+    }
+    fn count(self) -> usize {
+        self.cursor.len()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cursor.skip_front(n);
+        self.next()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Windows<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (_, offset) = self.cursor.next_back()?;
+        Some(self.window(offset))
+    }
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.cursor.skip_back(n);
+        self.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Windows<'a, T> {}
+impl<'a, T> FusedIterator for Windows<'a, T> {}
+
+impl<T, S> Slice2DWindows<T> for S
+where
+    S: Shape2D + SlicePtr<T>,
+{
+    fn windows(&self, shape: (usize, usize)) -> Windows<'_, T> {
+        Windows::new(self, shape, (1, 1), false)
+    }
+    fn windows_with_step(&self, shape: (usize, usize), step: (usize, usize)) -> Windows<'_, T> {
+        Windows::new(self, shape, step, false)
+    }
+    fn col_major_windows(&self, shape: (usize, usize)) -> Windows<'_, T> {
+        Windows::new(self, shape, (1, 1), true)
+    }
+    fn col_major_windows_with_step(
+        &self,
+        shape: (usize, usize),
+        step: (usize, usize),
+    ) -> Windows<'_, T> {
+        Windows::new(self, shape, step, true)
+    }
+}
+
+// windows of a padded view, they may reach into the border like the view
+// itself. every window is a padded view of its own
+pub struct PaddedWindows<'b, 'a, T> {
+    view: &'b Padded<'a, T>,
+    shape: (usize, usize),
+    cursor: IndexCursor,
+}
+
+impl<'b, 'a, T: Clone> PaddedWindows<'b, 'a, T> {
+    pub fn new(view: &'b Padded<'a, T>, shape: (usize, usize)) -> PaddedWindows<'b, 'a, T> {
+        PaddedWindows {
+            view,
+            shape,
+            cursor: IndexCursor::from_parts(
+                calc_window_count(view.get_row(), shape.0, 1),
+                calc_window_count(view.get_col(), shape.1, 1),
+                0,
+                0,
+                false,
+            ),
+        }
+    }
+    #[inline(always)]
+    fn window(&self, (r, c): (usize, usize)) -> Padded<'b, T> {
+        self.view
+            .get((r..r + self.shape.0, c..c + self.shape.1))
+            .unwrap()
+    }
+}
+
+impl<'b, 'a, T: Clone> Iterator for PaddedWindows<'b, 'a, T> {
+    type Item = Padded<'b, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (idx, _) = self.cursor.next()?;
+        Some(self.window(idx))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.len(), Some(self.cursor.len()))
+    }
+    fn count(self) -> usize {
+        self.cursor.len()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cursor.skip_front(n);
+        self.next()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'b, 'a, T: Clone> DoubleEndedIterator for PaddedWindows<'b, 'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (idx, _) = self.cursor.next_back()?;
+        Some(self.window(idx))
+    }
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.cursor.skip_back(n);
+        self.next_back()
+    }
+}
+
+impl<'b, 'a, T: Clone> ExactSizeIterator for PaddedWindows<'b, 'a, T> {}
+impl<'b, 'a, T: Clone> FusedIterator for PaddedWindows<'b, 'a, T> {}
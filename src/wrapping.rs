@@ -0,0 +1,304 @@
+use crate::{
+    slice::{Shape2D, Slice2D, Slice2DMut, Slice2DRaw, Slice2DRawRef},
+    utils::calc_2d_index,
+};
+use core::{
+    convert::TryFrom,
+    marker::PhantomData,
+    ops::{Index, IndexMut, Range, RangeFull, RangeInclusive},
+};
+
+// Toroidal views, coordinates wrap around the edges of the source.
+// A view is a window of `shape` cells starting from `origin`, which may
+// cross the edges but is never larger than the source, so that no cell
+// shows up twice. Indexing and ranges are relative to `origin` and wrap
+// modulo the window. A range may only cross the edge of a window that
+// spans the whole source along that axis, otherwise its cells would not
+// be next to each other in the source.
+
+pub struct Wrapping<'a, T> {
+    src: Slice2DRaw<T>,
+    origin: (usize, usize),
+    shape: (usize, usize),
+    _marker: PhantomData<&'a T>,
+}
+
+pub struct WrappingMut<'a, T> {
+    src: Slice2DRaw<T>,
+    origin: (usize, usize),
+    shape: (usize, usize),
+    _marker: PhantomData<&'a mut T>,
+}
+
+// range of coordinates along one axis
+pub trait WrappingRange {
+    // `(start, len)` on an axis of `len` cells, `None` if the range is
+    // reversed or longer than the axis
+    fn calc_range(&self, len: usize) -> Option<(isize, usize)>;
+}
+
+impl WrappingRange for Range<isize> {
+    fn calc_range(&self, len: usize) -> Option<(isize, usize)> {
+        let n = usize::try_from(self.end.checked_sub(self.start)?).ok()?;
+        if n <= len {
+            Some((self.start, n))
+        } else {
+            None
+        }
+    }
+}
+impl WrappingRange for RangeInclusive<isize> {
+    fn calc_range(&self, len: usize) -> Option<(isize, usize)> {
+        (*self.start()..self.end().checked_add(1)?).calc_range(len)
+    }
+}
+impl WrappingRange for RangeFull {
+    fn calc_range(&self, len: usize) -> Option<(isize, usize)> {
+        Some((0, len))
+    }
+}
+
+pub trait WrappingIndex<'a, T> {
+    type Ref;
+    fn get(self, view: &Wrapping<'a, T>) -> Option<Self::Ref>;
+}
+
+pub trait WrappingIndexMut<'a, T> {
+    type Ref;
+    type RefMut;
+    fn get(self, view: &'a WrappingMut<'_, T>) -> Option<Self::Ref>;
+    fn get_mut(self, view: &'a mut WrappingMut<'_, T>) -> Option<Self::RefMut>;
+}
+
+// `i` steps away from `origin` on an axis of `len` cells, `len > 0`
+#[inline(always)]
+fn wrap(origin: usize, i: isize, len: usize) -> usize {
+    let i = i.rem_euclid(len as isize) as usize;
+    if i < len - origin {
+        origin + i
+    } else {
+        i - (len - origin)
+    }
+}
+
+// element of the source at `idx` of the window, `None` if it is empty
+#[inline(always)]
+fn ptr_at<T>(
+    src: &Slice2DRaw<T>,
+    origin: (usize, usize),
+    shape: (usize, usize),
+    idx: (isize, isize),
+) -> Option<*const T> {
+    if shape.0 == 0 || shape.1 == 0 {
+        return None;
+    }
+    let r = wrap(origin.0, idx.0.rem_euclid(shape.0 as isize), src.get_row());
+    let c = wrap(origin.1, idx.1.rem_euclid(shape.1 as isize), src.get_col());
+    unsafe { Some(src.slice.offset(calc_2d_index(r, c, src))) }
+}
+
+// origin of `n` cells from `start` in a window of `shape` cells from
+// `origin` on an axis of `len` cells
+#[inline]
+fn sub_window(origin: usize, shape: usize, len: usize, start: isize, n: usize) -> Option<usize> {
+    if shape == 0 {
+        return Some(origin);
+    }
+    let start = start.rem_euclid(shape as isize);
+    if shape < len && start as usize + n > shape {
+        None
+    } else {
+        Some(wrap(origin, start, len))
+    }
+}
+
+// origin and shape of a sub-window
+fn calc_window<S: Shape2D, R1: WrappingRange, R2: WrappingRange>(
+    src: &S,
+    origin: (usize, usize),
+    shape: (usize, usize),
+    rows: &R1,
+    cols: &R2,
+) -> Option<((usize, usize), (usize, usize))> {
+    let (rs, rn) = rows.calc_range(shape.0)?;
+    let (cs, cn) = cols.calc_range(shape.1)?;
+    let r = sub_window(origin.0, shape.0, src.get_row(), rs, rn)?;
+    let c = sub_window(origin.1, shape.1, src.get_col(), cs, cn)?;
+    Some(((r, c), (rn, cn)))
+}
+
+impl<'a, T> Wrapping<'a, T> {
+    pub fn new(src: Slice2D<'a, T>) -> Wrapping<'a, T> {
+        let src = *src.get_slice_2d_raw();
+        let shape = (src.get_row(), src.get_col());
+        Wrapping {
+            src,
+            origin: (0, 0),
+            shape,
+            _marker: PhantomData,
+        }
+    }
+    #[inline]
+    pub fn get_shape(&self) -> (usize, usize) {
+        self.shape
+    }
+    #[inline]
+    pub fn get<I>(&self, index: I) -> Option<I::Ref>
+    where
+        I: WrappingIndex<'a, T>,
+    {
+        index.get(self)
+    }
+}
+
+impl<'a, T> Clone for Wrapping<'a, T> {
+    fn clone(&self) -> Self {
+        Wrapping {
+            src: self.src,
+            origin: self.origin,
+            shape: self.shape,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a> WrappingIndex<'a, T> for (isize, isize) {
+    type Ref = &'a T;
+
+    #[inline]
+    fn get(self, view: &Wrapping<'a, T>) -> Option<Self::Ref> {
+        ptr_at(&view.src, view.origin, view.shape, self).map(|p| unsafe { &*p })
+    }
+}
+
+impl<'a, T, R1, R2> WrappingIndex<'a, T> for (R1, R2)
+where
+    T: 'a,
+    R1: WrappingRange,
+    R2: WrappingRange,
+{
+    type Ref = Wrapping<'a, T>;
+
+    fn get(self, view: &Wrapping<'a, T>) -> Option<Self::Ref> {
+        let (origin, shape) = calc_window(&view.src, view.origin, view.shape, &self.0, &self.1)?;
+        Some(Wrapping {
+            src: view.src,
+            origin,
+            shape,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'a, T> Index<(isize, isize)> for Wrapping<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: (isize, isize)) -> &T {
+        unsafe { &*ptr_at(&self.src, self.origin, self.shape, index).expect("out of range") }
+    }
+}
+
+impl<'a, T> WrappingMut<'a, T> {
+    pub fn new(src: Slice2DMut<'a, T>) -> WrappingMut<'a, T> {
+        let src = *src.get_slice_2d_raw();
+        let shape = (src.get_row(), src.get_col());
+        WrappingMut {
+            src,
+            origin: (0, 0),
+            shape,
+            _marker: PhantomData,
+        }
+    }
+    #[inline]
+    pub fn get_shape(&self) -> (usize, usize) {
+        self.shape
+    }
+    #[inline]
+    pub fn get<'b, I>(&'b self, index: I) -> Option<I::Ref>
+    where
+        I: WrappingIndexMut<'b, T>,
+    {
+        index.get(self)
+    }
+    #[inline]
+    pub fn get_mut<'b, I>(&'b mut self, index: I) -> Option<I::RefMut>
+    where
+        I: WrappingIndexMut<'b, T>,
+    {
+        index.get_mut(self)
+    }
+}
+
+impl<'b, T: 'b> WrappingIndexMut<'b, T> for (isize, isize) {
+    type Ref = &'b T;
+    type RefMut = &'b mut T;
+
+    #[inline]
+    fn get(self, view: &'b WrappingMut<'_, T>) -> Option<Self::Ref> {
+        ptr_at(&view.src, view.origin, view.shape, self).map(|p| unsafe { &*p })
+    }
+    #[inline]
+    fn get_mut(self, view: &'b mut WrappingMut<'_, T>) -> Option<Self::RefMut> {
+        ptr_at(&view.src, view.origin, view.shape, self).map(|p| unsafe { &mut *(p as *mut T) })
+    }
+}
+
+impl<'b, T, R1, R2> WrappingIndexMut<'b, T> for (R1, R2)
+where
+    T: 'b,
+    R1: WrappingRange,
+    R2: WrappingRange,
+{
+    type Ref = Wrapping<'b, T>;
+    type RefMut = WrappingMut<'b, T>;
+
+    fn get(self, view: &'b WrappingMut<'_, T>) -> Option<Self::Ref> {
+        let (origin, shape) = calc_window(&view.src, view.origin, view.shape, &self.0, &self.1)?;
+        Some(Wrapping {
+            src: view.src,
+            origin,
+            shape,
+            _marker: PhantomData,
+        })
+    }
+    fn get_mut(self, view: &'b mut WrappingMut<'_, T>) -> Option<Self::RefMut> {
+        let (origin, shape) = calc_window(&view.src, view.origin, view.shape, &self.0, &self.1)?;
+        Some(WrappingMut {
+            src: view.src,
+            origin,
+            shape,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'a, T> Index<(isize, isize)> for WrappingMut<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: (isize, isize)) -> &T {
+        unsafe { &*ptr_at(&self.src, self.origin, self.shape, index).expect("out of range") }
+    }
+}
+
+impl<'a, T> IndexMut<(isize, isize)> for WrappingMut<'a, T> {
+    #[inline]
+    fn index_mut(&mut self, index: (isize, isize)) -> &mut T {
+        unsafe {
+            &mut *(ptr_at(&self.src, self.origin, self.shape, index).expect("out of range")
+                as *mut T)
+        }
+    }
+}
+
+impl<'a, T> From<WrappingMut<'a, T>> for Wrapping<'a, T> {
+    fn from(w: WrappingMut<'a, T>) -> Self {
+        Wrapping {
+            src: w.src,
+            origin: w.origin,
+            shape: w.shape,
+            _marker: PhantomData,
+        }
+    }
+}
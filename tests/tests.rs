@@ -1,3 +1,9 @@
+#![allow(
+    clippy::zero_prefixed_literal,
+    clippy::option_map_unit_fn,
+    clippy::map_clone
+)]
+
 use slice_2d::prelude::*;
 
 #[test]
@@ -299,6 +305,8 @@ fn slice_2d_split_mut() {
     assert_eq!(tr.get_shape(), (R, COL - C));
     assert_eq!(bl.get_shape(), (ROW - R, C));
     assert_eq!(br.get_shape(), (ROW - R, COL - C));
+    assert_eq!(tr[(1, 0)], 8);
+    assert_eq!(bl[(0, 1)], 11);
     tl[(0, 0)] = 0;
     tr[(0, 0)] = 0;
     bl[(0, 0)] = 0;
@@ -335,7 +343,7 @@ fn slice_2d_iter() {
 
     assert_eq!(
         vs.col_iter()
-            .map(|c| c.map(|e| *e).collect())
+            .map(|c| c.iter().map(|e| *e).collect())
             .collect::<Vec<Vec<_>>>(),
         vec![
             vec![00, 05, 10, 15],
@@ -368,7 +376,7 @@ fn slice_2d_iter_mut() {
 
     assert_eq!(
         vs.col_iter()
-            .map(|c| c.map(|e| *e).collect())
+            .map(|c| c.iter().map(|e| *e).collect())
             .collect::<Vec<Vec<_>>>(),
         vec![
             vec![00, 05, 10, 15],
@@ -405,7 +413,7 @@ fn slice_2d_iter_mut() {
 
     assert_eq!(
         vs.col_iter_mut()
-            .map(|c| c.map(|e| *e).collect())
+            .map(|c| c.into_iter().map(|e| *e).collect())
             .collect::<Vec<Vec<_>>>(),
         vec![
             vec![00, 05, 10, 15],
@@ -524,10 +532,12 @@ fn slice_2d_eq() {
     assert_ne!(s5, s4);
     assert_eq!(s5, s5);
 
-    assert_eq!(
+    // same pointer and shape, but different strides
+    assert_ne!(
         s4.get((..ROW.min(COL), 0)).unwrap(),
         s5.get((..ROW.min(COL), 0)).unwrap()
-    )
+    );
+    assert_eq!(s4.get((0, ..)).unwrap(), s5.get((0, ..COL)).unwrap());
 }
 
 #[test]
@@ -653,3 +663,1686 @@ fn slice_2d_empty() {
     assert_eq!(s1.col_iter().flatten().count(), 0);
     assert_eq!(s2.col_iter().flatten().count(), 0);
 }
+
+#[test]
+fn col_major() {
+    const ROW: usize = 3;
+    const COL: usize = 4;
+    let v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = ColMajorSlice2D::from_slice(v.as_slice(), ROW, COL);
+    for i in 0..ROW {
+        for j in 0..COL {
+            assert_eq!(s[(i, j)], (j * ROW + i) as i32);
+        }
+    }
+    assert_eq!(s.get((ROW, 0)), None);
+    assert_eq!(s.get((0, COL)), None);
+
+    assert_eq!(
+        s.col_slice_iter().collect::<Vec<_>>(),
+        vec![&[0, 1, 2], &[3, 4, 5], &[6, 7, 8], &[9, 10, 11]]
+    );
+    assert_eq!(s.try_col_slice_iter().map(|c| c.len()), Some(COL));
+    assert!(s.try_row_slice_iter().is_none());
+    assert!(Slice2D::from_slice(v.as_slice(), ROW, COL)
+        .try_row_slice_iter()
+        .is_some());
+    assert_eq!(
+        s.row_iter()
+            .map(|r| r.map(|e| *e).collect())
+            .collect::<Vec<Vec<_>>>(),
+        vec![vec![0, 3, 6, 9], vec![1, 4, 7, 10], vec![2, 5, 8, 11]]
+    );
+
+    // equal to the row-major view of the transposed data
+    let t = vec![0, 3, 6, 9, 1, 4, 7, 10, 2, 5, 8, 11];
+    assert_eq!(s, Slice2D::from_slice(t.as_slice(), ROW, COL));
+    assert_ne!(s, Slice2D::from_slice(v.as_slice(), ROW, COL));
+
+    let sub = s.get((1..3, 1..3)).unwrap();
+    assert_eq!(
+        sub.col_slice_iter().collect::<Vec<_>>(),
+        vec![&[4, 5], &[7, 8]]
+    );
+
+    let [[tl, tr], [bl, br]] = s.split_at((1, 2)).unwrap();
+    assert_eq!(tl, Slice2D::from_slice(&[0, 3], 1, 2));
+    assert_eq!(tr, Slice2D::from_slice(&[6, 9], 1, 2));
+    assert_eq!(bl, Slice2D::from_slice(&[1, 4, 2, 5], 2, 2));
+    assert_eq!(br, Slice2D::from_slice(&[7, 10, 8, 11], 2, 2));
+}
+
+#[test]
+fn col_major_mut() {
+    const ROW: usize = 3;
+    const COL: usize = 4;
+    let mut v = vec![0; ROW * COL];
+    let mut s = ColMajorSlice2DMut::from_slice(v.as_mut_slice(), ROW, COL);
+    s[(1, 2)] = 1;
+    assert_eq!(v[2 * ROW + 1], 1);
+
+    let mut s = ColMajorSlice2DMut::from_slice(v.as_mut_slice(), ROW, COL);
+    s.fill_with({
+        let mut counter = 0;
+        move || {
+            counter += 1;
+            counter
+        }
+    });
+    assert_eq!(v, vec![1, 5, 9, 2, 6, 10, 3, 7, 11, 4, 8, 12]);
+
+    let mut s = ColMajorSlice2DMut::from_slice(v.as_mut_slice(), ROW, COL);
+    let [[mut tl, mut tr], [mut bl, mut br]] = s.split_at_mut((1, 2)).unwrap();
+    tl.fill(-1);
+    tr.fill(-2);
+    bl.fill(-3);
+    br.fill(-4);
+    s.col_slice_iter_mut().for_each(|c| c[0] *= 10);
+    assert!(s.try_row_slice_iter_mut().is_none());
+    assert!(s.try_col_slice_iter_mut().is_some());
+    assert_eq!(v, vec![-10, -3, -3, -10, -3, -3, -20, -4, -4, -20, -4, -4]);
+}
+
+#[test]
+#[should_panic(expected = "rows are not contiguous")]
+fn col_major_row_slices() {
+    let v = [0; 6];
+    let s = ColMajorSlice2D::from_slice(&v, 2, 3);
+    s.row_slice_iter();
+}
+
+#[test]
+#[should_panic(expected = "slice does not contain enough space.")]
+fn col_major_size_overflow() {
+    let v = [0; 6];
+    ColMajorSlice2D::from_slice(&v, usize::MAX / 2 + 1, 2);
+}
+
+#[test]
+#[should_panic(expected = "slice does not contain enough space.")]
+fn col_major_mut_size_overflow() {
+    let mut v = [0; 6];
+    ColMajorSlice2DMut::from_slice(&mut v, usize::MAX / 2 + 1, 2);
+}
+
+#[test]
+fn col_major_hash() {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    fn hash<T: Hash>(t: &T) -> u64 {
+        let mut h = DefaultHasher::new();
+        t.hash(&mut h);
+        h.finish()
+    }
+    // equal views over different buffers
+    let a = [0, 1, 2, 3, 4, 5];
+    let b = [9, 0, 1, 2, 3, 4, 5];
+    let s = ColMajorSlice2D::from_slice(&a, 2, 3);
+    let t = ColMajorSlice2D::from_slice(&b[1..], 2, 3);
+    assert_eq!(s, t);
+    assert_eq!(hash(&s), hash(&t));
+    let mut c = a;
+    let m = ColMajorSlice2DMut::from_slice(&mut c, 2, 3);
+    assert_eq!(hash(&m), hash(&s));
+    assert_ne!(hash(&s), hash(&ColMajorSlice2D::from_slice(&a, 3, 2)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_2d() {
+    const ROW: usize = 4;
+    const COL: usize = 5;
+    let v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let vs = Slice2D::from_slice(v.as_slice(), ROW, COL);
+    let mut v2d = Vec2D::from_vec(v.clone(), ROW, COL);
+
+    assert_eq!(v2d.get_shape(), (ROW, COL));
+    assert_eq!(v2d, vs);
+    assert_eq!(vs, v2d);
+    assert_eq!(v2d, Vec2D::from_fn(ROW, COL, |(i, j)| (i * COL + j) as i32));
+    assert_eq!(v2d.as_slice(), Some(v.as_slice()));
+
+    for i in 0..ROW {
+        for j in 0..COL {
+            assert_eq!(v2d[(i, j)], (i * COL + j) as i32);
+            assert_eq!(v2d.get((i, j)), Some(&((i * COL + j) as i32)));
+        }
+    }
+    assert_eq!(v2d.get((ROW, 0)), None);
+    assert_eq!(v2d.get((1..3, 2..4)), vs.get((1..3, 2..4)));
+
+    let [t, b] = v2d.split_at_horizontally(1).unwrap();
+    assert_eq!(t.get_shape(), (1, COL));
+    assert_eq!(b.get_shape(), (ROW - 1, COL));
+
+    assert_eq!(
+        v2d.col_iter()
+            .map(|c| c.iter().map(|e| *e).collect())
+            .collect::<Vec<Vec<_>>>(),
+        vs.col_iter()
+            .map(|c| c.iter().map(|e| *e).collect())
+            .collect::<Vec<Vec<_>>>(),
+    );
+
+    v2d.swap((0, 0), (ROW - 1, COL - 1));
+    assert_eq!(v2d[(0, 0)], (ROW * COL - 1) as i32);
+    v2d[(0, 0)] = 0;
+    v2d.get_mut((.., 0)).unwrap().fill(-1);
+    assert!(v2d.col_iter().next().unwrap().iter().all(|e| *e == -1));
+
+    let mut s = Slice2DMut::from(&mut v2d);
+    s.fill(1);
+    assert_eq!(v2d, Vec2D::from_elem(ROW, COL, 1));
+
+    let e: Vec2D<()> = Vec2D::from_vec(vec![(); ROW * COL], ROW, COL);
+    assert_eq!(e.row_iter().flatten().count(), ROW * COL);
+    assert_eq!(e.col_iter().flatten().count(), ROW * COL);
+    assert!(Vec2D::<i32>::new().is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "vec does not match the shape.")]
+fn vec_2d_shape_mismatch() {
+    Vec2D::from_vec(vec![0; 5], 2, 3);
+}
+
+#[cfg(feature = "const_generics")]
+#[test]
+fn array_2d() {
+    const ROW: usize = 3;
+    const COL: usize = 4;
+    let v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let vs = Slice2D::from_slice(v.as_slice(), ROW, COL);
+    let mut a = Array2D::<i32, ROW, COL>::from_fn(|(i, j)| (i * COL + j) as i32);
+
+    assert_eq!(a.get_shape(), (ROW, COL));
+    assert_eq!(a, vs);
+    assert_eq!(vs, a);
+    assert_eq!(
+        a,
+        Array2D::new([[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]])
+    );
+    assert_eq!(a[(1, 2)], 6);
+    assert_eq!(a.get((1..3, 1..)), vs.get((1..3, 1..)));
+    assert_eq!(a.get((ROW, 0)), None);
+
+    assert_eq!(a.row::<1>(), &[4, 5, 6, 7]);
+    assert_eq!(
+        a.col::<2>().iter().map(|e| *e).collect::<Vec<_>>(),
+        vec![2, 6, 10]
+    );
+
+    let t = a.transpose();
+    assert_eq!(t.get_shape(), (COL, ROW));
+    for i in 0..ROW {
+        for j in 0..COL {
+            assert_eq!(t[(j, i)], a[(i, j)]);
+        }
+    }
+    assert_eq!(t.transpose(), a);
+
+    a.row_mut::<0>().iter_mut().for_each(|e| *e = -1);
+    a.col_mut::<3>().fill(-2);
+    assert_eq!(
+        a.into_inner(),
+        [[-1, -1, -1, -2], [4, 5, 6, -2], [8, 9, 10, -2]]
+    );
+
+    let mut a = Array2D::<i32, ROW, COL>::default();
+    a.fill(7);
+    a.swap((0, 0), (2, 3));
+    assert_eq!(a, Array2D::new([[7; COL]; ROW]));
+    a[(0, 0)] = 1;
+    a.swap((0, 0), (2, 3));
+    assert_eq!((a[(0, 0)], a[(2, 3)]), (7, 1));
+
+    // the parts share one pointer into the inline storage
+    let [[mut tl, _], [_, mut br]] = a.split_at_mut((1, 2)).unwrap();
+    tl[(0, 0)] = 2;
+    br[(1, 1)] = 3;
+    assert_eq!((a[(0, 0)], a[(2, 3)]), (2, 3));
+
+    let s: Array2D<String, 2, 3> = Array2D::from_fn(|(i, j)| format!("{}{}", i, j));
+    let s = s.transpose();
+    assert_eq!(s[(2, 1)], "12");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_2d_insert_remove() {
+    let mut v = Vec2D::new();
+    v.push_row(&[1, 2, 3]);
+    v.push_row(&[7, 8, 9]);
+    v.insert_row(1, &[4, 5, 6]);
+    assert_eq!(v, Vec2D::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3));
+
+    v.push_col(vec![30, 60, 90]);
+    v.insert_col(0, vec![0, 0, 0]);
+    v.insert_col(2, 10..13);
+    assert_eq!(
+        v.as_mut_slice(),
+        &[
+            0, 1, 10, 2, 3, 30, // row 1
+            0, 4, 11, 5, 6, 60, // row 2
+            0, 7, 12, 8, 9, 90, // row 3
+        ]
+    );
+
+    assert_eq!(v.remove_col(2), vec![10, 11, 12]);
+    assert_eq!(v.remove_col(0), vec![0, 0, 0]);
+    assert_eq!(v.remove_col(3), vec![30, 60, 90]);
+    assert_eq!(v.remove_row(0), vec![1, 2, 3]);
+    assert_eq!(v, Vec2D::from_vec(vec![4, 5, 6, 7, 8, 9], 2, 3));
+
+    let b = vec![-1, -2, -3, -4, -5, -6];
+    v.insert_rows_from(1, Slice2D::from_slice(b.as_slice(), 2, 3));
+    assert_eq!(v.get_shape(), (4, 3));
+    assert_eq!(
+        v.as_mut_slice(),
+        &[4, 5, 6, -1, -2, -3, -4, -5, -6, 7, 8, 9]
+    );
+
+    v.truncate_cols(2);
+    assert_eq!(v.as_mut_slice(), &[4, 5, -1, -2, -4, -5, 7, 8]);
+    v.truncate_rows(1);
+    assert_eq!(v.as_mut_slice(), &[4, 5]);
+    v.truncate_cols(0);
+    assert_eq!(v.get_shape(), (1, 0));
+    v.push_col(vec![1]);
+    assert_eq!(v.as_mut_slice(), &[1]);
+
+    let mut v = Vec2D::new();
+    v.push_col(vec![1, 2]);
+    v.push_col(vec![3, 4]);
+    assert_eq!(v.as_mut_slice(), &[1, 3, 2, 4]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_2d_insert_remove_drop() {
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    let mut v = Vec2D::from_elem(3, 4, rc.clone());
+    assert_eq!(Rc::strong_count(&rc), 13);
+    v.insert_col(1, vec![rc.clone(), rc.clone(), rc.clone()]);
+    v.push_row(&vec![rc.clone(); 5]);
+    assert_eq!(Rc::strong_count(&rc), 21);
+    v.remove_col(0);
+    v.remove_row(2);
+    assert_eq!(Rc::strong_count(&rc), 13);
+    v.truncate_cols(1);
+    assert_eq!(Rc::strong_count(&rc), 4);
+    v.truncate_rows(0);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "column length does not match.")]
+fn vec_2d_insert_col_mismatch() {
+    let mut v = Vec2D::from_elem(3, 4, 0);
+    v.insert_col(1, vec![1, 2]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_2d_insert_col_mismatch_intact() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut v = Vec2D::from_fn(3, 4, |(i, j)| (i * 4 + j).to_string());
+    let w = v.clone();
+    for len in [2, 4] {
+        let col = (0..len).map(|i| i.to_string());
+        assert!(catch_unwind(AssertUnwindSafe(|| v.insert_col(1, col))).is_err());
+        assert_eq!(v, w);
+    }
+
+    // a grid without columns keeps its rows
+    let mut v = Vec2D::from_elem(2, 0, 0);
+    v.push_col([1, 2]);
+    assert_eq!(v.row_iter().flatten().copied().collect::<Vec<_>>(), [1, 2]);
+    let mut v = Vec2D::from_elem(2, 0, 0);
+    assert!(catch_unwind(AssertUnwindSafe(|| v.push_col([1, 2, 3]))).is_err());
+    assert_eq!(v.get_shape(), (2, 0));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_2d_capacity() {
+    use slice_2d::vec_2d::TryReserveError;
+
+    let mut v = Vec2D::with_capacity(4, 3);
+    assert_eq!((v.row_capacity(), v.col_capacity()), (4, 3));
+    v.resize(2, 2, 1);
+    assert_eq!(v.as_slice(), None);
+    let ptr = &v[(0, 0)] as *const i32;
+    v.push_col(vec![2, 2]);
+    v.push_row(&[3, 3, 3]);
+    assert_eq!(&v[(0, 0)] as *const i32, ptr);
+    assert_eq!(v, Vec2D::from_vec(vec![1, 1, 2, 1, 1, 2, 3, 3, 3], 3, 3));
+
+    v.reserve_cols(2);
+    assert!(v.col_capacity() >= 5);
+    v.reserve_rows(10);
+    assert!(v.row_capacity() >= 13);
+    assert_eq!(v, Vec2D::from_vec(vec![1, 1, 2, 1, 1, 2, 3, 3, 3], 3, 3));
+
+    let mut counter = 0;
+    v.resize_with(4, 5, || {
+        counter += 1;
+        counter
+    });
+    assert_eq!(
+        v.make_contiguous(),
+        &[
+            1, 1, 2, 1, 2, // row 1
+            1, 1, 2, 3, 4, // row 2
+            3, 3, 3, 5, 6, // row 3
+            7, 8, 9, 10, 11, // row 4
+        ]
+    );
+    v.resize(2, 3, 0);
+    assert_eq!(v, Vec2D::from_vec(vec![1, 1, 2, 1, 1, 2], 2, 3));
+    v.shrink_to_fit();
+    assert_eq!((v.row_capacity(), v.col_capacity()), (2, 3));
+    assert_eq!(v, Vec2D::from_vec(vec![1, 1, 2, 1, 1, 2], 2, 3));
+
+    assert_eq!(
+        v.try_reserve((usize::MAX, 0)),
+        Err(TryReserveError::CapacityOverflow)
+    );
+    assert_eq!(
+        v.try_reserve((1, usize::MAX / 2)),
+        Err(TryReserveError::CapacityOverflow)
+    );
+    assert_eq!(v.try_reserve((1, 1)), Ok(()));
+
+    let mut e: Vec2D<()> = Vec2D::new();
+    e.resize(3, 4, ());
+    e.reserve_cols(100);
+    assert_eq!(e.get_shape(), (3, 4));
+    assert_eq!(e.row_iter().flatten().count(), 12);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_2d_into_iter_drain() {
+    use std::rc::Rc;
+
+    let v = Vec2D::from_fn(3, 4, |(i, j)| i * 4 + j);
+    assert_eq!(
+        v.clone().into_iter().collect::<Vec<_>>(),
+        (0..12).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        v.clone().into_rows().collect::<Vec<_>>(),
+        vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9, 10, 11]]
+    );
+    assert_eq!(
+        v.clone().into_rows().rev().collect::<Vec<_>>(),
+        vec![vec![8, 9, 10, 11], vec![4, 5, 6, 7], vec![0, 1, 2, 3]]
+    );
+    let mut rows = v.clone().into_rows();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows.next_back(), Some(vec![8, 9, 10, 11]));
+    assert_eq!(rows.next(), Some(vec![0, 1, 2, 3]));
+    assert_eq!(rows.len(), 1);
+
+    let mut w = v.clone();
+    w.reserve_cols(3);
+    assert_eq!(w.into_flat_vec(), ((0..12).collect::<Vec<_>>(), (3, 4)));
+
+    let mut w = v.clone();
+    let d = w.drain_rows(1..2);
+    assert_eq!(d.len(), 4);
+    assert_eq!(d.collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+    assert_eq!(w, Vec2D::from_vec(vec![0, 1, 2, 3, 8, 9, 10, 11], 2, 4));
+
+    let mut w = v.clone();
+    assert_eq!(
+        w.drain_cols(1..3).rev().collect::<Vec<_>>(),
+        vec![10, 9, 6, 5, 2, 1]
+    );
+    assert_eq!(w, Vec2D::from_vec(vec![0, 3, 4, 7, 8, 11], 3, 2));
+    w.drain_cols(..);
+    assert_eq!(w.get_shape(), (3, 0));
+
+    let rc = Rc::new(());
+    let mut w = Vec2D::from_elem(4, 5, rc.clone());
+    let mut d = w.drain_rows(1..);
+    d.next();
+    drop(d);
+    assert_eq!(w.get_shape(), (1, 5));
+    assert_eq!(Rc::strong_count(&rc), 6);
+    let mut d = w.drain_cols(..2);
+    d.next_back();
+    drop(d);
+    assert_eq!(w.get_shape(), (1, 3));
+    assert_eq!(Rc::strong_count(&rc), 4);
+    let mut it = w.clone().into_iter();
+    it.next();
+    drop(it);
+    assert_eq!(Rc::strong_count(&rc), 4);
+    drop(w.into_rows());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_2d_retain() {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        rc::Rc,
+    };
+
+    let mut v = Vec2D::from_fn(4, 5, |(i, j)| (i * 5 + j) as i32);
+    v.retain_rows(|r| r[0] % 10 == 0);
+    assert_eq!(
+        v,
+        Vec2D::from_vec(vec![0, 1, 2, 3, 4, 10, 11, 12, 13, 14], 2, 5)
+    );
+    v.retain_cols(|c| c[0] % 2 == 1);
+    assert_eq!(v, Vec2D::from_vec(vec![1, 3, 11, 13], 2, 2));
+    v.retain_rows_mut(|r| {
+        r[0] += 1;
+        true
+    });
+    v.retain_cols_mut(|mut c| {
+        c.iter_mut().for_each(|e| *e *= 2);
+        true
+    });
+    assert_eq!(v, Vec2D::from_vec(vec![4, 6, 24, 26], 2, 2));
+    v.retain_cols(|_| false);
+    assert_eq!(v.get_shape(), (2, 0));
+
+    let rc = Rc::new(());
+    let mut v = Vec2D::from_elem(4, 5, rc.clone());
+    let mut n = 0;
+    let r = catch_unwind(AssertUnwindSafe(|| {
+        v.retain_rows(|_| {
+            n += 1;
+            if n == 3 {
+                panic!();
+            }
+            n == 1
+        })
+    }));
+    assert!(r.is_err());
+    assert_eq!(v.get_shape(), (3, 5));
+    assert_eq!(Rc::strong_count(&rc), 16);
+
+    let mut n = 0;
+    let r = catch_unwind(AssertUnwindSafe(|| {
+        v.retain_cols(|_| {
+            n += 1;
+            if n == 4 {
+                panic!();
+            }
+            n != 2
+        })
+    }));
+    assert!(r.is_err());
+    assert_eq!(v.get_shape(), (3, 4));
+    assert_eq!(Rc::strong_count(&rc), 13);
+    drop(v);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_2d_transpose_in_place() {
+    for &(row, col) in &[
+        (0, 0),
+        (1, 1),
+        (1, 5),
+        (5, 1),
+        (3, 3),
+        (3, 5),
+        (7, 4),
+        (16, 9),
+    ] {
+        let v = Vec2D::from_fn(row, col, |(i, j)| format!("{},{}", i, j));
+        let mut t = v.clone();
+        t.reserve_cols(3);
+        t.transpose_in_place();
+        assert_eq!(t.get_shape(), (col, row));
+        for i in 0..row {
+            for j in 0..col {
+                assert_eq!(t[(j, i)], v[(i, j)]);
+            }
+        }
+        t.transpose_in_place();
+        assert_eq!(t, v);
+    }
+
+    let mut e = Vec2D::from_elem(3, 4, ());
+    e.transpose_in_place();
+    assert_eq!(e.get_shape(), (4, 3));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_2d_aligned() {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    fn hash<T: Hash>(t: &T) -> u64 {
+        let mut h = DefaultHasher::new();
+        t.hash(&mut h);
+        h.finish()
+    }
+    fn assert_aligned<T>(v: &Vec2D<T>, align: usize) {
+        assert_eq!(v.pitch() % align, 0);
+        for i in 0..v.get_row() {
+            assert_eq!(&v[(i, 0)] as *const T as usize % align, 0);
+        }
+    }
+
+    let mut v = Vec2D::from_fn_aligned(3, 5, 64, |(i, j)| (i * 5 + j) as u8);
+    let u = Vec2D::from_fn(3, 5, |(i, j)| (i * 5 + j) as u8);
+    assert_eq!(v.align(), 64);
+    assert_eq!(v.pitch(), 64);
+    assert_aligned(&v, 64);
+    assert_eq!(v, u);
+    assert_eq!(hash(&v), hash(&u));
+    assert_eq!(v.row_iter().flatten().count(), 15);
+    assert_eq!(v.clone(), u);
+    assert_aligned(&v.clone(), 64);
+
+    v.fill(7);
+    assert!(v.row_iter().flatten().all(|&e| e == 7));
+    v.push_row(&[1; 5]);
+    v.push_col(0..4);
+    assert_aligned(&v, 64);
+    v.transpose_in_place();
+    assert_eq!(v.get_shape(), (6, 4));
+    assert_aligned(&v, 64);
+
+    // with room for the padding, the rows are spread out in place
+    let mut t = Vec2D::with_capacity_aligned(8, 5, 64);
+    for i in 0..3 {
+        t.push_row(&[i * 5, i * 5 + 1, i * 5 + 2, i * 5 + 3, i * 5 + 4]);
+    }
+    let (p, cap) = (&t[(0, 0)] as *const i32, t.row_capacity());
+    t.transpose_in_place();
+    assert_eq!(t.get_shape(), (5, 3));
+    assert_aligned(&t, 64);
+    assert_eq!(&t[(0, 0)] as *const i32, p);
+    assert_eq!(t.row_capacity(), cap);
+    for i in 0..5 {
+        for j in 0..3 {
+            assert_eq!(t[(i, j)], (j * 5 + i) as i32);
+        }
+    }
+
+    let (data, shape) = v.into_flat_vec();
+    assert_eq!(shape, (6, 4));
+    assert_eq!(data.len(), 24);
+    assert_eq!(&data[16..], &[7, 7, 7, 1, 0, 1, 2, 3]);
+
+    // packing gives up the alignment only if the rows would lose it
+    let mut w = Vec2D::from_fn_aligned(3, 5, 64, |(i, j)| (i * 5 + j) as u8);
+    assert_eq!(w.make_contiguous(), u.as_slice().unwrap());
+    assert_eq!((w.align(), w.pitch()), (1, 5));
+    let mut w = Vec2D::<u32>::with_capacity_aligned(3, 8, 16);
+    w.resize(3, 4, 1);
+    assert_eq!(w.pitch(), 32);
+    assert_eq!(w.make_contiguous(), &[1; 12]);
+    assert_eq!((w.align(), w.pitch()), (16, 16));
+    assert_aligned(&w, 16);
+
+    let w = Vec2D::<[u8; 3]>::with_capacity_aligned(2, 2, 32);
+    assert_eq!(w.col_capacity(), 32);
+    assert_eq!(w.pitch(), 96);
+
+    let w = Vec2D::<u64>::with_capacity_aligned(2, 2, 1);
+    assert_eq!(w.align(), std::mem::align_of::<u64>());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "alignment is not a power of two.")]
+fn vec_2d_aligned_invalid() {
+    Vec2D::<u8>::with_capacity_aligned(1, 1, 3);
+}
+
+#[cfg(feature = "const_generics")]
+#[test]
+fn inline_grid() {
+    use std::rc::Rc;
+
+    let mut g = InlineGrid::<i32, 12>::from_fn(2, 3, |(i, j)| (i * 3 + j) as i32);
+    assert_eq!(g.capacity(), 12);
+    assert_eq!(g, Slice2D::from_slice(&[0, 1, 2, 3, 4, 5], 2, 3));
+
+    g.push_row(&[6, 7, 8]);
+    g.push_col([10, 11, 12]);
+    assert_eq!(g.as_slice(), &[0, 1, 2, 10, 3, 4, 5, 11, 6, 7, 8, 12]);
+    assert_eq!(g[(2, 3)], 12);
+
+    let c = g.pop_col().unwrap();
+    assert_eq!(c.get_shape(), (3, 1));
+    assert_eq!(c.as_slice(), &[10, 11, 12]);
+    let r = g.pop_row().unwrap();
+    assert_eq!(r.as_slice(), &[6, 7, 8]);
+    assert_eq!(g.as_slice(), &[0, 1, 2, 3, 4, 5]);
+
+    g.resize(3, 4, -1);
+    assert_eq!(g.as_slice(), &[0, 1, 2, -1, 3, 4, 5, -1, -1, -1, -1, -1]);
+    g.resize(2, 2, 0);
+    assert_eq!(g.as_slice(), &[0, 1, 3, 4]);
+    g.fill(9);
+    assert_eq!(g.as_slice(), &[9; 4]);
+
+    let mut e = InlineGrid::<i32, 4>::new();
+    assert!(e.pop_row().is_none());
+    e.push_col([1, 2, 3]);
+    assert_eq!(e.get_shape(), (3, 1));
+    e.push_row(&[4]);
+    assert_eq!(e.as_slice(), &[1, 2, 3, 4]);
+
+    let rc = Rc::new(());
+    let mut g = InlineGrid::<_, 16>::from_elem(3, 3, rc.clone());
+    g.truncate_cols(2);
+    assert_eq!(Rc::strong_count(&rc), 7);
+    g.resize_with(3, 4, || rc.clone());
+    assert_eq!(Rc::strong_count(&rc), 13);
+    g.push_col(vec![rc.clone(); 3]);
+    assert_eq!(Rc::strong_count(&rc), 16);
+    drop(g.pop_col());
+    let h = g.clone();
+    assert_eq!(Rc::strong_count(&rc), 25);
+    drop(g);
+    assert_eq!(Rc::strong_count(&rc), 13);
+    drop(h);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[cfg(feature = "const_generics")]
+#[test]
+fn inline_grid_push_col_mismatch_intact() {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        rc::Rc,
+    };
+
+    let rc = Rc::new(());
+    let mut g = InlineGrid::<_, 16>::from_fn(3, 2, |(i, j)| (i * 2 + j, rc.clone()));
+    let w = g.clone();
+    for len in [2, 4] {
+        let col = (0..len).map(|i| (i, rc.clone()));
+        assert!(catch_unwind(AssertUnwindSafe(|| g.push_col(col))).is_err());
+        assert_eq!(g, w);
+    }
+    assert_eq!(Rc::strong_count(&rc), 13);
+
+    // a grid without columns keeps its rows
+    let mut g = InlineGrid::<i32, 4>::from_elem(2, 0, 0);
+    g.push_col([1, 2]);
+    assert_eq!(g.as_slice(), &[1, 2]);
+    let mut g = InlineGrid::<i32, 4>::from_elem(2, 0, 0);
+    assert!(catch_unwind(AssertUnwindSafe(|| g.push_col([1, 2, 3]))).is_err());
+    assert_eq!(g.get_shape(), (2, 0));
+}
+
+#[cfg(feature = "const_generics")]
+#[test]
+#[should_panic(expected = "capacity exceeded.")]
+fn inline_grid_capacity_exceeded() {
+    let mut g = InlineGrid::<u8, 6>::from_elem(2, 3, 0);
+    g.push_row(&[0, 0, 0]);
+}
+
+#[test]
+fn slice_2d_with_stride() {
+    // 3x4 frame with a pitch of 6, starting from the second element
+    let v = (0..20).collect::<Vec<i32>>();
+    let s = Slice2D::from_slice_with_stride(v.as_slice(), 1, 3, 4, 6);
+    assert_eq!(s.get_shape(), (3, 4));
+    assert_eq!(
+        s,
+        Slice2D::from_slice(&[1, 2, 3, 4, 7, 8, 9, 10, 13, 14, 15, 16], 3, 4)
+    );
+    assert_eq!(s.get((2, 3)), Some(&16));
+    assert_eq!(s.get((0, 4)), None);
+
+    // the last row does not need the padding
+    let s = Slice2D::from_slice_with_stride(&v[..17], 1, 3, 4, 6);
+    assert_eq!(s[(2, 3)], 16);
+    let s = Slice2D::from_slice_with_stride(v.as_slice(), 20, 0, 4, 6);
+    assert!(s.is_empty());
+
+    let mut v = vec![0; 20];
+    let mut s = Slice2DMut::from_slice_with_stride(v.as_mut_slice(), 2, 3, 2, 7);
+    s.fill(1);
+    assert_eq!(v.iter().sum::<i32>(), 6);
+    assert_eq!(&v[..10], &[0, 0, 1, 1, 0, 0, 0, 0, 0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "slice does not contain enough space.")]
+fn slice_2d_with_stride_out_of_range() {
+    let v = [0; 16];
+    Slice2D::from_slice_with_stride(&v, 1, 3, 4, 6);
+}
+
+#[test]
+#[should_panic(expected = "slice does not contain enough space.")]
+fn slice_2d_with_stride_overflow() {
+    let v = [0; 16];
+    Slice2D::from_slice_with_stride(&v, 0, 3, 4, usize::MAX / 2 + 1);
+}
+
+#[test]
+#[should_panic(expected = "rows overlap each other.")]
+fn slice_2d_with_stride_overlap() {
+    let mut v = [0; 16];
+    Slice2DMut::from_slice_with_stride(&mut v, 0, 3, 4, 3);
+}
+
+#[test]
+fn slice_2d_transposed() {
+    const ROW: usize = 3;
+    const COL: usize = 4;
+    let v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+    let t = s.transposed();
+    assert_eq!(t.get_shape(), (COL, ROW));
+    for i in 0..ROW {
+        for j in 0..COL {
+            assert_eq!(t[(j, i)], s[(i, j)]);
+        }
+    }
+    assert_eq!(t.get((0, ROW)), None);
+    assert_eq!(t.transposed(), s);
+    assert_eq!(
+        t,
+        Slice2D::from_slice(&[0, 4, 8, 1, 5, 9, 2, 6, 10, 3, 7, 11], COL, ROW)
+    );
+    assert_eq!(
+        t.row_iter()
+            .map(|r| r.map(|e| *e).collect())
+            .collect::<Vec<Vec<_>>>(),
+        s.col_iter()
+            .map(|c| c.iter().map(|e| *e).collect())
+            .collect::<Vec<Vec<_>>>(),
+    );
+    assert_eq!(t.col_slice_iter().next(), Some(&[0, 1, 2, 3][..]));
+
+    let sub = t.get((1..3, 1..)).unwrap();
+    assert_eq!(sub, Slice2D::from_slice(&[5, 9, 6, 10], 2, 2));
+    let [[tl, tr], [bl, br]] = t.split_at((1, 2)).unwrap();
+    assert_eq!(tl, Slice2D::from_slice(&[0, 4], 1, 2));
+    assert_eq!(tr, Slice2D::from_slice(&[8], 1, 1));
+    assert_eq!(bl, Slice2D::from_slice(&[1, 5, 2, 6, 3, 7], 3, 2));
+    assert_eq!(br, Slice2D::from_slice(&[9, 10, 11], 3, 1));
+
+    let mut v = vec![0; ROW * COL];
+    let s = Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL);
+    let mut t = s.transposed();
+    t[(3, 0)] = 1;
+    let [_, mut b] = t.split_at_horizontally_mut(2).unwrap();
+    b.fill_with({
+        let mut counter = 1;
+        move || {
+            counter += 1;
+            counter
+        }
+    });
+    assert_eq!(v, vec![0, 0, 2, 5, 0, 0, 3, 6, 0, 0, 4, 7]);
+}
+
+#[test]
+fn slice_2d_flip() {
+    const ROW: usize = 3;
+    const COL: usize = 4;
+    let v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+
+    let f = s.flip_rows();
+    assert_eq!(
+        f,
+        Slice2D::from_slice(&[8, 9, 10, 11, 4, 5, 6, 7, 0, 1, 2, 3], ROW, COL)
+    );
+    assert_eq!(f.get((ROW, 0)), None);
+    assert_eq!(f.get((0, COL)), None);
+    assert_eq!(f.flip_rows(), s);
+
+    let f = s.flip_cols();
+    assert_eq!(
+        f,
+        Slice2D::from_slice(&[3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8], ROW, COL)
+    );
+    assert_eq!(
+        f.get((1, 1..3)).unwrap(),
+        Slice2D::from_slice(&[6, 5], 1, 2)
+    );
+
+    let f = s.rotated_180();
+    assert_eq!(
+        f.row_iter().flatten().map(|e| *e).collect::<Vec<_>>(),
+        (0..(ROW * COL) as i32).rev().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        f.get((1.., ..2)).unwrap(),
+        Slice2D::from_slice(&[7, 6, 3, 2], 2, 2)
+    );
+    let [t, b] = f.split_at_horizontally(1).unwrap();
+    assert_eq!(t, Slice2D::from_slice(&[11, 10, 9, 8], 1, COL));
+    assert_eq!(b.flip_rows(), s.get((..2, ..)).unwrap().flip_cols());
+    assert_eq!(f.transposed().get((0, ..)).unwrap().get_shape(), (1, ROW));
+
+    let e = Slice2D::<i32>::from_slice(&[], 0, 4);
+    assert!(e.rotated_180().is_empty());
+
+    let mut v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL);
+    let mut f = s.flip_rows();
+    f[(0, 0)] = -1;
+    f.get_mut((1.., 1..)).unwrap().fill(0);
+    let [_, mut r] = f.split_at_vertically_mut(3).unwrap();
+    r.swap((0, 0), (2, 0));
+    assert_eq!(v, vec![0, 0, 0, 11, 4, 0, 0, 0, -1, 9, 10, 0]);
+}
+
+#[test]
+fn slice_2d_step_by() {
+    const ROW: usize = 5;
+    const COL: usize = 6;
+    let v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+
+    let t = s.step_by(2, 3);
+    assert_eq!(t.get_shape(), (3, 2));
+    assert_eq!(t, Slice2D::from_slice(&[0, 3, 12, 15, 24, 27], 3, 2));
+    assert_eq!(t.get((3, 0)), None);
+    assert_eq!(
+        t.get((1.., 1)).unwrap(),
+        Slice2D::from_slice(&[15, 27], 2, 1)
+    );
+    assert_eq!(t.step_by(2, 1), Slice2D::from_slice(&[0, 3, 24, 27], 2, 2));
+    assert_eq!(s.step_by(1, 1), s);
+    // steps past the end select only the first row or column
+    for step in [isize::MAX as usize, usize::MAX] {
+        assert_eq!(s.step_by(step, ROW), Slice2D::from_slice(&[0, 5], 1, 2));
+    }
+    let [l, r] = t.split_at_vertically(1).unwrap();
+    assert_eq!(l, Slice2D::from_slice(&[0, 12, 24], 3, 1));
+    assert_eq!(r, Slice2D::from_slice(&[3, 15, 27], 3, 1));
+
+    // range indexing with steps
+    let t = s.get(((1..5).step_by(2), ..)).unwrap();
+    assert_eq!(t.get_shape(), (2, COL));
+    assert_eq!(t, s.get((1.., ..)).unwrap().step_by(2, 1));
+    let t = s.get(((0..ROW).step_by(2), (1..COL).step_by(2))).unwrap();
+    assert_eq!(
+        t,
+        Slice2D::from_slice(&[1, 3, 5, 13, 15, 17, 25, 27, 29], 3, 3)
+    );
+    let t = s.get((2, (0..6).step_by(4))).unwrap();
+    assert_eq!(t, Slice2D::from_slice(&[12, 16], 1, 2));
+    let t = s.get(((0..4).step_by(3), 5)).unwrap();
+    assert_eq!(t, Slice2D::from_slice(&[5, 23], 2, 1));
+    assert!(s.get(((0..6).step_by(5), ..)).is_none());
+    assert!(s.get(((0..7).step_by(5), ..)).is_none());
+    assert!(s.get(((0..0).step_by(5), ..)).unwrap().is_empty());
+
+    // Bayer planes
+    let mut v = vec![0; ROW * COL];
+    let mut s = Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL);
+    s.get_mut(((0..ROW).step_by(2), (0..COL).step_by(2)))
+        .unwrap()
+        .fill(1);
+    s.get_mut(((1..ROW).step_by(2), (1..COL).step_by(2)))
+        .unwrap()
+        .fill(2);
+    let s = s.step_by(1, 2);
+    assert_eq!(
+        s.row_iter().flatten().map(|e| *e).collect::<Vec<_>>(),
+        vec![1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1]
+    );
+    assert_eq!(v.iter().filter(|&&e| e == 2).count(), 6);
+}
+
+#[test]
+#[should_panic(expected = "step is zero.")]
+fn slice_2d_step_by_zero() {
+    let v = [0; 4];
+    Slice2D::from_slice(&v, 2, 2).step_by(0, 1);
+}
+
+#[test]
+fn slice_2d_diag() {
+    const ROW: usize = 3;
+    const COL: usize = 4;
+    let v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+
+    let d = s.diag(0);
+    assert_eq!(d.len(), 3);
+    assert_eq!(d.stride(), COL as isize + 1);
+    assert_eq!(d.iter().copied().collect::<Vec<_>>(), vec![0, 5, 10]);
+    assert_eq!(d.iter().rev().copied().collect::<Vec<_>>(), vec![10, 5, 0]);
+    assert_eq!(d[1], 5);
+    assert_eq!(d.get(3), None);
+    assert_eq!(
+        s.diag(1).iter().copied().collect::<Vec<_>>(),
+        vec![1, 6, 11]
+    );
+    assert_eq!(s.diag(2).iter().copied().collect::<Vec<_>>(), vec![2, 7]);
+    assert_eq!(s.diag(-1).iter().copied().collect::<Vec<_>>(), vec![4, 9]);
+    assert!(s.diag(4).is_empty());
+    assert!(s.diag(-3).is_empty());
+
+    let a = s.anti_diag(0);
+    assert_eq!(a.stride(), COL as isize - 1);
+    assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+    assert_eq!(
+        s.anti_diag(1).iter().copied().collect::<Vec<_>>(),
+        vec![2, 5, 8]
+    );
+    assert_eq!(
+        s.anti_diag(-1).iter().copied().collect::<Vec<_>>(),
+        vec![7, 10]
+    );
+    assert_eq!(s.anti_diag(3).iter().copied().collect::<Vec<_>>(), vec![0]);
+
+    // diagonals of other views
+    let t = s.transposed();
+    assert_eq!(
+        t.diag(-1).iter().copied().collect::<Vec<_>>(),
+        vec![1, 6, 11]
+    );
+    let f = s.get((1.., 1..)).unwrap();
+    assert_eq!(f.diag(0).iter().copied().collect::<Vec<_>>(), vec![5, 10]);
+
+    let mut v = vec![0; 16];
+    let mut s = Slice2DMut::from_slice(v.as_mut_slice(), 4, 4);
+    s.diag_mut(0).fill(1);
+    s.diag_mut(1).fill(2);
+    s.diag_mut(-1).fill_with({
+        let mut counter = 2;
+        move || {
+            counter += 1;
+            counter
+        }
+    });
+    s.anti_diag_mut(-3)[0] = 9;
+    assert_eq!(
+        v,
+        vec![
+            1, 2, 0, 0, //
+            3, 1, 2, 0, //
+            0, 4, 1, 2, //
+            0, 0, 5, 9, //
+        ]
+    );
+    let m = (0..16).collect::<Vec<usize>>();
+    let trace = Slice2D::from_slice(&m, 4, 4).diag(0).iter().sum::<usize>();
+    assert_eq!(trace, 30);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn strided_slice() {
+    const ROW: usize = 4;
+    const COL: usize = 3;
+    let v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+
+    let c = s.col_iter().nth(1).unwrap();
+    assert_eq!(c.len(), ROW);
+    assert_eq!(c[2], 7);
+    assert_eq!(c.get(4), None);
+    // walk it twice
+    assert_eq!(c.iter().sum::<i32>(), 22);
+    assert_eq!(
+        c.iter().rev().copied().collect::<Vec<_>>(),
+        vec![10, 7, 4, 1]
+    );
+    assert_eq!(c.rev().to_vec(), vec![10, 7, 4, 1]);
+    assert_eq!(c.rev().rev().to_vec(), c.to_vec());
+    let (a, b) = c.split_at(1);
+    assert_eq!(a.to_vec(), vec![1]);
+    assert_eq!(b.to_vec(), vec![4, 7, 10]);
+    let (a, b) = c.split_at(ROW);
+    assert_eq!(a.len(), ROW);
+    assert!(b.is_empty());
+    assert!(c.split_at(0).0.rev().is_empty());
+    assert_eq!(
+        StridedSlice::from_col(&s, 2).unwrap().to_vec(),
+        vec![2, 5, 8, 11]
+    );
+    assert!(StridedSlice::from_col(&s, 3).is_none());
+
+    let mut v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let mut s = Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL);
+    let mut c = s.col_iter_mut().next().unwrap();
+    c.swap(0, 3);
+    c[1] = -1;
+    let (mut a, mut b) = c.split_at_mut(2);
+    a.fill(0);
+    b.copy_from_slice(&[20, 30]);
+    assert_eq!(c.to_vec(), vec![0, 0, 20, 30]);
+    c.rev().copy_from_slice(&[1, 2, 3, 4]);
+    assert_eq!(v, vec![4, 1, 2, 3, 4, 5, 2, 7, 8, 1, 10, 11]);
+}
+
+#[test]
+#[should_panic(expected = "slice length does not match.")]
+fn strided_slice_copy_mismatch() {
+    let mut v = [0; 6];
+    let mut s = Slice2DMut::from_slice(&mut v, 3, 2);
+    s.col_iter_mut().next().unwrap().copy_from_slice(&[1, 2]);
+}
+
+#[test]
+fn slice_2d_wrapping() {
+    const ROW: usize = 3;
+    const COL: usize = 4;
+    let mut v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+
+    let w = s.wrapping();
+    assert_eq!(w.get_shape(), (ROW, COL));
+    assert_eq!(w[(0, 0)], 0);
+    assert_eq!(w[(-1, -1)], 11);
+    assert_eq!(w[(3, 4)], 0);
+    assert_eq!(w[(-4, 9)], 9);
+    assert_eq!(w.get((isize::MIN, isize::MAX)), Some(&s[(1, 3)]));
+
+    // windows crossing the edges
+    let r = w.get((-1..1, 3..=5)).unwrap();
+    assert_eq!(r.get_shape(), (2, 3));
+    assert_eq!(r[(0, 0)], 11);
+    assert_eq!(r[(0, 1)], 8);
+    assert_eq!(r[(1, 2)], 1);
+    // sub-windows wrap in their own shape
+    assert_eq!(r[(2, 3)], 11);
+    assert_eq!(r[(-1, -1)], 1);
+    let r2 = r.get((1..2, 1..3)).unwrap();
+    assert_eq!(r2.get_shape(), (1, 2));
+    assert_eq!(r2[(0, 0)], 0);
+    assert_eq!(r2[(0, 3)], 1);
+    assert_eq!(r.get((.., ..)).unwrap().get_shape(), (2, 3));
+    assert_eq!(r.get((.., ..)).unwrap()[(0, 0)], 11);
+    assert!(r.get((.., 2..4)).is_none());
+    assert!(r.get((.., 0..4)).is_none());
+    assert_eq!(w.get((.., ..)).unwrap().get_shape(), (ROW, COL));
+    assert_eq!(w.get((0..0, ..)).unwrap().get((0, 0)), None);
+    assert!(w.get((0..4, ..)).is_none());
+    let (start, end) = (2, 1);
+    assert!(w.get((.., start..end)).is_none());
+
+    // transposed views wrap in their own shape
+    let t = s.transposed().wrapping();
+    assert_eq!(t[(-1, 0)], 3);
+
+    let e = Slice2D::<i32>::from_slice(&[], 0, 0).wrapping();
+    assert_eq!(e.get((0, 0)), None);
+
+    let mut m = Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL).wrapping();
+    m[(-1, -1)] = -1;
+    *m.get_mut((3, 1)).unwrap() = -2;
+    let mut r = m.get_mut((2..4, -1..1)).unwrap();
+    r[(0, 0)] = -3;
+    r[(1, 1)] = -4;
+    assert_eq!(m.get((-1..0, ..)).unwrap()[(0, 3)], -3);
+    assert_eq!(&v[..], &[-4, -2, 2, 3, 4, 5, 6, 7, 8, 9, 10, -3]);
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn slice_2d_wrapping_empty() {
+    let w = Slice2D::<i32>::from_slice(&[], 2, 0).wrapping();
+    let _ = w[(0, 0)];
+}
+
+#[test]
+fn slice_2d_padded() {
+    let v = [1, 2, 3, 4, 5, 6];
+    let s = Slice2D::from_slice(&v, 2, 3);
+    let row =
+        |p: &Padded<'_, i32>, r: usize| p.row_iter().nth(r).unwrap().copied().collect::<Vec<_>>();
+
+    let p = s.padded(1, 1, 2, 2, BorderMode::Constant(0));
+    assert_eq!(p.get_shape(), (4, 7));
+    assert_eq!(row(&p, 0), vec![0; 7]);
+    assert_eq!(row(&p, 1), vec![0, 0, 1, 2, 3, 0, 0]);
+    assert_eq!(p[(2, 4)], 6);
+    assert_eq!(p.get((4, 0)), None);
+    assert_eq!(p.row_iter().len(), 4);
+    assert_eq!(p.row_iter().map(|r| r.len()).sum::<usize>(), 28);
+
+    let p = s.padded(0, 0, 2, 2, BorderMode::Clamp);
+    assert_eq!(row(&p, 0), vec![1, 1, 1, 2, 3, 3, 3]);
+    let p = s.padded(0, 0, 2, 2, BorderMode::Reflect);
+    assert_eq!(row(&p, 0), vec![2, 1, 1, 2, 3, 3, 2]);
+    let p = s.padded(0, 0, 2, 2, BorderMode::Reflect101);
+    assert_eq!(row(&p, 0), vec![3, 2, 1, 2, 3, 2, 1]);
+    let p = s.padded(0, 0, 2, 2, BorderMode::Wrap);
+    assert_eq!(row(&p, 0), vec![2, 3, 1, 2, 3, 1, 2]);
+
+    // borders wider than the source
+    let p = s.padded(3, 0, 0, 0, BorderMode::Reflect);
+    assert_eq!(
+        p.row_iter()
+            .map(|mut r| *r.next().unwrap())
+            .collect::<Vec<_>>(),
+        vec![4, 4, 1, 1, 4]
+    );
+    let p = s.padded(3, 0, 0, 0, BorderMode::Reflect101);
+    assert_eq!(
+        p.row_iter()
+            .map(|mut r| *r.next().unwrap())
+            .collect::<Vec<_>>(),
+        vec![4, 1, 4, 1, 4]
+    );
+
+    // windows over the padded view
+    let p = s.padded(1, 1, 1, 1, BorderMode::Clamp);
+    let w = p.get((0..3, 2..)).unwrap();
+    assert_eq!(w.get_shape(), (3, 3));
+    assert_eq!(row(&w, 0), vec![2, 3, 3]);
+    assert_eq!(row(&w, 2), vec![5, 6, 6]);
+    // 3x3 neighbourhoods of every cell of the source
+    let mut ws = p.windows((3, 3));
+    assert_eq!(ws.len(), 6);
+    let first = ws.next().unwrap();
+    assert_eq!(row(&first, 0), vec![1, 1, 2]);
+    assert_eq!(row(&first, 2), vec![4, 4, 5]);
+    assert_eq!(row(&ws.next_back().unwrap(), 2), vec![5, 6, 6]);
+    assert_eq!(row(&ws.nth(1).unwrap(), 1), vec![2, 3, 3]);
+    assert_eq!(ws.len(), 2);
+    let sums = p
+        .windows((3, 3))
+        .map(|w| w.row_iter().flatten().sum::<i32>())
+        .collect::<Vec<_>>();
+    assert_eq!(sums, vec![21, 27, 33, 30, 36, 42]);
+    assert_eq!(p.windows((5, 1)).count(), 0);
+    let w = p.get(((0..4).step_by(3), (0..5).step_by(2))).unwrap();
+    assert_eq!(w.get_shape(), (2, 3));
+    assert_eq!(row(&w, 1), vec![4, 5, 6]);
+    assert!(p.get((0..5, ..)).is_none());
+
+    // views of other shapes
+    let p = s.transposed().padded(0, 0, 1, 0, BorderMode::Wrap);
+    assert_eq!(row(&p, 2), vec![6, 3, 6]);
+    let e = Slice2D::<i32>::from_slice(&[], 0, 0);
+    let p = e.padded(1, 0, 1, 0, BorderMode::Constant(7));
+    assert_eq!(p[(0, 0)], 7);
+}
+
+#[test]
+#[should_panic(expected = "nothing to pad from, the slice is empty.")]
+fn slice_2d_padded_empty() {
+    let e = Slice2D::<i32>::from_slice(&[], 0, 2);
+    e.padded(1, 0, 0, 0, BorderMode::Clamp);
+}
+
+#[test]
+fn slice_2d_stack() {
+    use slice_2d::stack::{hstack, hstack_mut, vstack, vstack_mut, ShapeMismatchError};
+
+    let a = [1, 2, 3, 4];
+    let b = [5, 6];
+    let c = [7, 8, 9, 10, 11, 12];
+    let parts = [
+        Slice2D::from_slice(&a, 2, 2),
+        Slice2D::from_slice(&b, 2, 1),
+        Slice2D::from_slice(&c, 3, 2).transposed().step_by(1, 2),
+    ];
+    let h = hstack(&parts).unwrap();
+    assert_eq!(h.get_shape(), (2, 5));
+    assert_eq!(h[(0, 2)], 5);
+    assert_eq!(h[(1, 4)], 12);
+    assert_eq!(h.get((2, 0)), None);
+    assert_eq!(h.get((0, 5)), None);
+    assert_eq!(
+        h.row_iter()
+            .map(|r| r.copied().collect())
+            .collect::<Vec<Vec<_>>>(),
+        vec![vec![1, 2, 5, 7, 11], vec![3, 4, 6, 8, 12]]
+    );
+    assert_eq!(h.row_iter().next().unwrap().len(), 5);
+    let v = [1, 2, 5, 7, 11, 3, 4, 6, 8, 12];
+    assert!(h == Slice2D::from_slice(&v, 2, 5));
+    assert!(h != Slice2D::from_slice(&v, 5, 2));
+
+    let parts = [
+        Slice2D::from_slice(&a, 2, 2),
+        Slice2D::from_slice(&b, 1, 2),
+        Slice2D::from_slice(&c, 0, 2),
+        Slice2D::from_slice(&c, 3, 2),
+    ];
+    let vs = vstack(&parts).unwrap();
+    assert_eq!(vs.get_shape(), (6, 2));
+    assert_eq!(vs[(2, 1)], 6);
+    assert_eq!(vs[(5, 0)], 11);
+    assert_eq!(vs.row_iter().len(), 6);
+    assert_eq!(
+        vs.row_iter().flatten().copied().collect::<Vec<_>>(),
+        (1..=12).collect::<Vec<_>>()
+    );
+    let t = [Slice2D::from_slice(&v, 2, 5).transposed()];
+    assert!(hstack(&t).unwrap() != vs);
+    let w = (1..=12).collect::<Vec<_>>();
+    assert!(vs == hstack(&[Slice2D::from_slice(&w, 6, 2)]).unwrap());
+
+    assert_eq!(
+        hstack(&[Slice2D::from_slice(&a, 2, 2), Slice2D::from_slice(&b, 1, 2)]).err(),
+        Some(ShapeMismatchError::Mismatch {
+            index: 1,
+            expected: 2,
+            found: 1
+        })
+    );
+    let z = vec![(); usize::MAX];
+    let wide = Slice2D::from_slice(&z, 1, usize::MAX);
+    assert_eq!(
+        hstack(&[wide.clone(), Slice2D::from_slice(&z, 1, 0), wide]).err(),
+        Some(ShapeMismatchError::Overflow { index: 2 })
+    );
+    assert!(vstack(&[Slice2D::from_slice(&a, 2, 2), Slice2D::from_slice(&b, 2, 1)]).is_err());
+    assert_eq!(hstack::<i32>(&[]).unwrap().get_shape(), (0, 0));
+
+    let mut a = [1, 2, 3, 4];
+    let mut b = [5, 6];
+    {
+        let (a0, a1) = a.split_at_mut(2);
+        let mut parts = [
+            Slice2DMut::from_slice(a0, 1, 2),
+            Slice2DMut::from_slice(a1, 1, 2),
+            Slice2DMut::from_slice(&mut b, 1, 2),
+        ];
+        let mut h = hstack_mut(&mut parts).unwrap();
+        h[(0, 5)] = 0;
+        *h.get_mut((0, 0)).unwrap() = -1;
+        for row in h.row_iter_mut() {
+            row.skip(2).for_each(|e| *e *= 10);
+        }
+        assert!(h
+            .row_iter()
+            .next()
+            .unwrap()
+            .eq([-1, 2, 30, 40, 50, 0].iter()));
+    }
+    assert_eq!((a, b), ([-1, 2, 30, 40], [50, 0]));
+    {
+        let mut parts = [
+            Slice2DMut::from_slice(&mut a, 2, 2),
+            Slice2DMut::from_slice(&mut b, 1, 2),
+        ];
+        let mut vs = vstack_mut(&mut parts).unwrap();
+        vs.row_iter_mut().flatten().for_each(|e| *e += 1);
+        vs[(2, 1)] = 7;
+    }
+    assert_eq!((a, b), ([0, 3, 31, 41], [51, 7]));
+}
+
+#[test]
+fn slice_2d_map_view() {
+    let v = [[255u8, 0, 0], [0, 255, 0], [0, 0, 255], [255, 255, 255]];
+    let s = Slice2D::from_slice(&v, 2, 2);
+    let luma = |p: &[u8; 3]| (p[0] as u32 * 299 + p[1] as u32 * 587 + p[2] as u32 * 114) / 1000;
+    let m = s.map_view(luma);
+    assert_eq!(m.get_shape(), (2, 2));
+    assert_eq!(m.get((0, 1)), Some(149));
+    assert_eq!(m.get((1, 1)), Some(255));
+    assert_eq!(m.get((2, 0)), None);
+    assert_eq!(
+        m.row_iter().map(|r| r.collect()).collect::<Vec<Vec<_>>>(),
+        vec![vec![76, 149], vec![29, 255]]
+    );
+    assert_eq!(
+        m.col_iter().map(|c| c.collect()).collect::<Vec<Vec<_>>>(),
+        vec![vec![76, 29], vec![149, 255]]
+    );
+
+    let w = (0..12).collect::<Vec<i32>>();
+    let s = Slice2D::from_slice(&w, 3, 4);
+    let d = s.map_view(|e| e * 2);
+    assert_eq!(d.row_iter().len(), 3);
+    let mut cols = d.col_iter();
+    cols.next();
+    assert_eq!(cols.size_hint(), (3, Some(3)));
+    let even = s.map_view(|&x| x % 2 == 0);
+    let sub = even.get((1.., 1..3)).unwrap();
+    assert_eq!(sub.get_shape(), (2, 2));
+    assert_eq!(sub.get((0, 0)), Some(false));
+    assert_eq!(sub.get((1, 1)), Some(true));
+    assert_eq!(sub.get((1, 0)), Some(false));
+    let sub = sub.get((.., 1..)).unwrap();
+    assert_eq!(
+        sub.row_iter().flatten().collect::<Vec<_>>(),
+        vec![true, true]
+    );
+    assert!(even.get((0..4, ..)).is_none());
+    assert_eq!(
+        s.transposed()
+            .map_view(|x| x * 10)
+            .row_iter()
+            .next()
+            .unwrap()
+            .collect::<Vec<_>>(),
+        vec![0, 40, 80]
+    );
+}
+
+#[test]
+fn slice_2d_iter_traits() {
+    const ROW: usize = 4;
+    const COL: usize = 3;
+    let mut v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+
+    let mut rows = s.row_iter();
+    assert_eq!(rows.len(), ROW);
+    assert_eq!(rows.size_hint(), (ROW, Some(ROW)));
+    assert!(rows.next().unwrap().eq([0, 1, 2].iter()));
+    assert!(rows.next_back().unwrap().eq([9, 10, 11].iter()));
+    assert_eq!(rows.len(), 2);
+    assert!(rows.nth_back(1).unwrap().eq([3, 4, 5].iter()));
+    assert!(rows.next().is_none());
+    assert!(rows.next_back().is_none());
+    assert_eq!(s.row_iter().count(), ROW);
+    assert!(s.row_iter().nth(2).unwrap().eq([6, 7, 8].iter()));
+    assert!(s.row_iter().nth(4).is_none());
+    assert!(s.row_iter().last().unwrap().eq([9, 10, 11].iter()));
+    assert_eq!(
+        s.row_iter()
+            .rev()
+            .step_by(2)
+            .map(|r| r.sum::<i32>())
+            .collect::<Vec<_>>(),
+        vec![30, 12]
+    );
+
+    let mut row = s.row_iter().nth(1).unwrap();
+    assert_eq!(row.len(), COL);
+    assert_eq!(row.nth(1), Some(&4));
+    assert_eq!(row.next_back(), Some(&5));
+    assert_eq!(row.next(), None);
+    assert_eq!(s.row_iter().nth(1).unwrap().nth_back(2), Some(&3));
+    assert_eq!(s.row_iter().nth(1).unwrap().rev().count(), COL);
+    assert_eq!(s.row_iter().nth(3).unwrap().last(), Some(&11));
+
+    let mut cols = s.col_iter();
+    assert_eq!(cols.len(), COL);
+    assert!(cols.next_back().unwrap().iter().eq([2, 5, 8, 11].iter()));
+    assert_eq!(cols.nth(1).unwrap()[3], 10);
+    assert!(cols.next().is_none());
+    let mut c = s.col_iter().next().unwrap().into_iter();
+    assert_eq!(c.nth(2), Some(&6));
+    assert_eq!(c.len(), 1);
+    assert_eq!(s.col_iter().next().unwrap().iter().nth_back(3), Some(&0));
+
+    assert_eq!(s.row_slice_iter().rev().nth(1), Some(&[6, 7, 8][..]));
+    assert_eq!(s.row_slice_iter().nth_back(3), Some(&[0, 1, 2][..]));
+    assert_eq!(s.row_slice_iter().zip(s.col_iter()).len(), ROW.min(COL));
+    let t = s.transposed();
+    assert_eq!(t.col_slice_iter().len(), ROW);
+    assert_eq!(t.col_slice_iter().nth(1), Some(&[3, 4, 5][..]));
+    assert_eq!(t.col_slice_iter().next_back(), Some(&[9, 10, 11][..]));
+
+    let mut m = Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL);
+    let mut rows = m.row_iter_mut();
+    let mut first = rows.next().unwrap();
+    let mut last = rows.next_back().unwrap();
+    assert_eq!(rows.len(), 2);
+    *first.next_back().unwrap() = -1;
+    *last.nth(1).unwrap() = -2;
+    m.row_iter_mut()
+        .rev()
+        .step_by(3)
+        .for_each(|r| r.for_each(|e| *e *= 10));
+    m.col_iter_mut().nth_back(1).unwrap().fill(0);
+    for r in m.row_slice_iter_mut().rev().skip(2) {
+        r[0] += 100;
+    }
+    assert!(m
+        .col_iter_mut()
+        .next()
+        .unwrap()
+        .iter()
+        .eq([100, 103, 6, 90].iter()));
+    let mut tm = Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL).transposed();
+    assert_eq!(tm.col_slice_iter_mut().rev().len(), ROW);
+    tm.col_slice_iter_mut().last().unwrap()[2] = 7;
+    assert_eq!(&v[..], &[100, 0, -10, 103, 0, 5, 6, 0, 8, 90, 0, 7]);
+}
+
+#[test]
+fn slice_2d_indexed_iter() {
+    const ROW: usize = 2;
+    const COL: usize = 3;
+    let mut v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+
+    let mut it = s.indexed_iter();
+    assert_eq!(it.len(), ROW * COL);
+    assert_eq!(it.next(), Some(((0, 0), &0)));
+    assert_eq!(it.nth(2), Some(((1, 0), &3)));
+    assert_eq!(it.next_back(), Some(((1, 2), &5)));
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.next(), Some(((1, 1), &4)));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+    assert!(s.indexed_iter().all(|((r, c), &e)| e == s[(r, c)]));
+    assert_eq!(
+        s.indexed_col_major_iter()
+            .map(|(i, &e)| (i, e))
+            .collect::<Vec<_>>(),
+        vec![
+            ((0, 0), 0),
+            ((1, 0), 3),
+            ((0, 1), 1),
+            ((1, 1), 4),
+            ((0, 2), 2),
+            ((1, 2), 5)
+        ]
+    );
+    assert_eq!(s.indexed_col_major_iter().nth_back(1), Some(((0, 2), &2)));
+    assert_eq!(s.indexed_iter().last(), Some(((1, 2), &5)));
+    assert_eq!(s.indexed_iter().nth(6), None);
+
+    // other views
+    let t = s.transposed().flip_rows();
+    assert!(t.indexed_iter().all(|((r, c), &e)| e == t[(r, c)]));
+    assert_eq!(t.indexed_iter().next(), Some(((0, 0), &2)));
+    let e = Slice2D::from_slice(v.as_slice(), 3, 0);
+    assert_eq!(e.indexed_iter().len(), 0);
+    assert_eq!(e.indexed_col_major_iter().next(), None);
+
+    let mut m = Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL);
+    m.indexed_iter_mut()
+        .for_each(|((r, c), e)| *e = (r * 10 + c) as i32);
+    m.indexed_col_major_iter_mut()
+        .rev()
+        .take(2)
+        .for_each(|(_, e)| *e = -*e);
+    assert_eq!(&v[..], &[0, 1, -2, 10, 11, -12]);
+}
+
+#[test]
+fn slice_2d_flat_iter() {
+    const ROW: usize = 3;
+    const COL: usize = 4;
+    let mut v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+
+    // contiguous
+    let mut it = s.iter();
+    assert_eq!(it.len(), ROW * COL);
+    assert_eq!(it.nth(5), Some(&5));
+    assert_eq!(it.next_back(), Some(&11));
+    assert_eq!(it.nth_back(1), Some(&9));
+    assert_eq!(it.copied().collect::<Vec<_>>(), vec![6, 7, 8]);
+    assert_eq!(s.iter().sum::<i32>(), 66);
+
+    // strided
+    let sub = s.get((1.., 1..3)).unwrap();
+    assert_eq!(sub.iter().copied().collect::<Vec<_>>(), vec![5, 6, 9, 10]);
+    assert_eq!(
+        sub.iter().rev().copied().collect::<Vec<_>>(),
+        vec![10, 9, 6, 5]
+    );
+    let mut it = sub.iter();
+    assert_eq!(it.nth(1), Some(&6));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.fold(0, |acc, &e| acc * 100 + e), 910);
+    let mut it = sub.iter();
+    assert_eq!(it.next_back(), Some(&10));
+    assert_eq!(it.next(), Some(&5));
+    let mut rest = vec![];
+    it.for_each(|&e| rest.push(e));
+    assert_eq!(rest, vec![6, 9]);
+    assert_eq!(sub.iter().nth(4), None);
+    assert_eq!(sub.iter().last(), Some(&10));
+    let t = s.transposed();
+    assert!(t.iter().eq(t.row_iter().flatten()));
+    let f = s.rotated_180();
+    assert_eq!(f.iter().next(), Some(&11));
+    assert_eq!(f.iter().count(), ROW * COL);
+    let e = Slice2D::<i32>::from_slice(&[], 0, 3);
+    assert_eq!(e.iter().next(), None);
+
+    // fill goes through the flat iterators
+    let mut m = Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL);
+    m.iter_mut().rev().take(2).for_each(|e| *e = -1);
+    let mut sub = m.get_mut((..2, 1..3)).unwrap();
+    sub.fill(0);
+    let mut n = 0;
+    sub.transposed().fill_with(|| {
+        n += 1;
+        n
+    });
+    assert_eq!(&v[..], &[0, 1, 3, 3, 4, 2, 4, 7, 8, 9, -1, -1]);
+    Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL).fill(7);
+    assert!(v.iter().all(|&e| e == 7));
+}
+
+#[test]
+fn slice_2d_into_iter() {
+    const ROW: usize = 3;
+    const COL: usize = 2;
+    let mut v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+
+    let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+    let mut n = 0;
+    for row in &s {
+        assert_eq!(row.len(), COL);
+        n += row.sum::<i32>();
+    }
+    assert_eq!(n, 15);
+    assert_eq!((&s).into_iter().len(), ROW);
+
+    // the consuming iterator borrows the data, not the view
+    let rows = {
+        let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+        s.into_iter()
+    };
+    assert_eq!(rows.last().unwrap().copied().collect::<Vec<_>>(), [4, 5]);
+
+    let mut m = Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL);
+    for row in &mut m {
+        row.for_each(|e| *e *= 2);
+    }
+    for row in &m {
+        assert_eq!(row.len(), COL);
+    }
+    for mut row in m {
+        *row.next().unwrap() += 1;
+    }
+    assert_eq!(&v[..], &[1, 2, 5, 6, 9, 10]);
+    let rows = Slice2DMut::from_slice(v.as_mut_slice(), ROW, COL).into_iter();
+    rows.for_each(|r| r.for_each(|e| *e = 1));
+    assert!(v.iter().all(|&e| e == 1));
+}
+
+#[test]
+fn slice_2d_into_iter_transposed() {
+    let mut v = [0, 1, 2, 3, 4, 5];
+    let s = Slice2D::from_slice(&v, 3, 2).transposed();
+    let rows = s
+        .into_iter()
+        .map(|r| r.copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, [[0, 2, 4], [1, 3, 5]]);
+
+    let m = Slice2DMut::from_slice(&mut v, 3, 2).transposed();
+    for (i, row) in m.into_iter().enumerate() {
+        row.for_each(|e| *e += i as i32 * 10);
+    }
+    assert_eq!(v, [0, 11, 2, 13, 4, 15]);
+}
+
+#[test]
+fn slice_2d_windows() {
+    const ROW: usize = 3;
+    const COL: usize = 4;
+    let v = (0..(ROW * COL) as i32).collect::<Vec<_>>();
+    let s = Slice2D::from_slice(v.as_slice(), ROW, COL);
+
+    let mut w = s.windows((2, 2));
+    assert_eq!(w.len(), 6);
+    let first = w.next().unwrap();
+    assert_eq!(first.get_row(), 2);
+    assert_eq!(first.get_col(), 2);
+    assert_eq!(first, s.get((0..2, 0..2)).unwrap());
+    assert_eq!(w.next().unwrap()[(0, 0)], 1);
+    assert_eq!(w.nth(1).unwrap()[(0, 0)], 4);
+    assert_eq!(w.next_back().unwrap()[(1, 1)], 11);
+    assert_eq!(w.len(), 1);
+    assert_eq!(
+        s.windows((2, 2))
+            .map(|w| w.iter().sum::<i32>())
+            .collect::<Vec<_>>(),
+        vec![10, 14, 18, 26, 30, 34]
+    );
+    assert!(s
+        .windows((2, 3))
+        .enumerate()
+        .all(|(i, w)| w == s.get((i / 2..i / 2 + 2, i % 2..i % 2 + 3)).unwrap()));
+    assert_eq!(
+        s.col_major_windows((2, 2))
+            .map(|w| w[(0, 0)])
+            .collect::<Vec<_>>(),
+        vec![0, 4, 1, 5, 2, 6]
+    );
+    assert_eq!(s.col_major_windows((2, 2)).nth_back(1).unwrap()[(0, 0)], 2);
+
+    // with steps
+    let w = s.windows_with_step((2, 2), (1, 2));
+    assert_eq!(w.len(), 4);
+    assert_eq!(w.map(|w| w[(0, 0)]).collect::<Vec<_>>(), vec![0, 2, 4, 6]);
+    let w = s.col_major_windows_with_step((1, 3), (2, 1));
+    assert_eq!(w.map(|w| w[(0, 0)]).collect::<Vec<_>>(), vec![0, 8, 1, 9]);
+    assert_eq!(s.windows_with_step((3, 4), (5, 5)).count(), 1);
+    let mut w = s.windows_with_step((2, 2), (isize::MAX as usize, usize::MAX));
+    assert_eq!(w.len(), 1);
+    assert_eq!(w.next().unwrap(), s.get((0..2, 0..2)).unwrap());
+    assert!(w.next().is_none());
+
+    // too large and other views
+    assert_eq!(s.windows((4, 1)).len(), 0);
+    assert_eq!(s.windows((4, 1)).next(), None);
+    let t = s.transposed();
+    assert_eq!(t.windows((4, 2)).len(), 2);
+    assert_eq!(t.windows((4, 2)).last().unwrap()[(3, 0)], 7);
+    assert_eq!(s.windows((ROW, COL)).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "window size is zero.")]
+fn slice_2d_windows_zero() {
+    let v = [0; 4];
+    Slice2D::from_slice(&v, 2, 2).windows((0, 1));
+}